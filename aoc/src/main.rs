@@ -0,0 +1,89 @@
+use std::{
+    io::Read,
+    path::{Path, PathBuf},
+};
+
+use clap::{Parser, Subcommand};
+use color_eyre::eyre::{eyre, WrapErr};
+use common::Mode;
+
+mod profile;
+mod report;
+mod solutions;
+mod verify;
+
+#[global_allocator]
+static ALLOCATOR: profile::CountingAllocator = profile::CountingAllocator;
+
+/// Runs the solver of a single Advent of Code 2022 puzzle.
+#[derive(Parser, Debug)]
+#[command(name = "aoc", args_conflicts_with_subcommands = true)]
+struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+    /// Day of the puzzle
+    #[arg(long, required = true, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+    /// Part of the puzzle, both parts are profiled when omitted with `--profile`
+    #[arg(long, required_unless_present = "profile", value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    /// Puzzle input, `-` for stdin. Defaults to `$AOC_INPUT_DIR/dayNN.txt`, then `inputs/dayNN.txt`
+    #[arg(long)]
+    input: Option<PathBuf>,
+    /// Reports the time, allocations and peak memory of parsing and solving
+    #[arg(long)]
+    profile: bool,
+    /// Skips malformed lines with a warning instead of failing, where the day supports it
+    #[arg(long)]
+    lenient: bool,
+    /// How answers are printed
+    #[arg(long, value_enum, default_value_t)]
+    format: report::Format,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Checks the solvers against the answers recorded in an answers file
+    Verify {
+        /// Answers file with `[[answer]]` tables of day, part, input and expected
+        #[arg(default_value = "answers.toml")]
+        answers: PathBuf,
+    },
+}
+
+fn read_input(day: u8, path: Option<&Path>) -> color_eyre::Result<String> {
+    if path != Some(Path::new("-")) {
+        return common::input::read(day, path);
+    }
+    let mut input = String::new();
+    std::io::stdin()
+        .read_to_string(&mut input)
+        .wrap_err("failed to read input from stdin")?;
+    Ok(input)
+}
+
+fn main() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+    let args = Args::parse();
+    if let Some(Command::Verify { answers }) = &args.command {
+        return verify::run(answers);
+    }
+    let Some(day) = args.day else {
+        unreachable!("clap requires the day without a subcommand");
+    };
+    let solution = solutions::find(day).ok_or_else(|| eyre!("day {day} is not solved"))?;
+    let input = read_input(day, args.input.as_deref())?;
+    let mode = match args.lenient {
+        true => Mode::Lenient,
+        false => Mode::Strict,
+    };
+    let parts = match args.part {
+        Some(part) => part..=part,
+        None => 1..=2,
+    };
+    for part in parts {
+        let run = solution(&input, part, mode)?;
+        report::print(args.format, day, part, &run, args.profile)?;
+    }
+    Ok(())
+}
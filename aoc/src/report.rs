@@ -0,0 +1,125 @@
+use clap::ValueEnum;
+use color_eyre::eyre::WrapErr;
+use serde::Serialize;
+
+use crate::{profile::Measurement, solutions::Run};
+
+/// How answers are printed.
+#[derive(ValueEnum, Debug, Clone, Copy, Default)]
+pub enum Format {
+    /// The answer alone, warnings go to stderr
+    #[default]
+    Text,
+    /// One JSON object per part
+    Json,
+}
+
+#[derive(Serialize)]
+struct Record<'a> {
+    day: u8,
+    part: u8,
+    answer: &'a str,
+    elapsed_ms: f64,
+    warnings: &'a [String],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    profile: Option<Profile>,
+}
+
+#[derive(Serialize)]
+struct Profile {
+    parse: Phase,
+    solve: Phase,
+}
+
+#[derive(Serialize)]
+struct Phase {
+    elapsed_ms: f64,
+    allocations: usize,
+    peak_bytes: usize,
+}
+
+impl From<Measurement> for Phase {
+    fn from(measurement: Measurement) -> Self {
+        Phase {
+            elapsed_ms: milliseconds(&measurement),
+            allocations: measurement.allocations,
+            peak_bytes: measurement.peak_bytes,
+        }
+    }
+}
+
+fn milliseconds(measurement: &Measurement) -> f64 {
+    measurement.elapsed.as_secs_f64() * 1000.0
+}
+
+/// Prints the answer of a part, with the cost of each phase if `profile` is set.
+pub fn print(
+    format: Format,
+    day: u8,
+    part: u8,
+    run: &Run,
+    profile: bool,
+) -> color_eyre::Result<()> {
+    match format {
+        Format::Text => {
+            for warning in &run.warnings {
+                eprintln!("warning: {warning}");
+            }
+            if profile {
+                println!("day {day} part {part}: {}", run.answer);
+                println!("  parse {}", run.parse);
+                println!("  solve {}", run.solve);
+            } else {
+                println!("{}", run.answer);
+            }
+        }
+        Format::Json => {
+            let record = Record {
+                day,
+                part,
+                answer: &run.answer,
+                elapsed_ms: milliseconds(&run.parse) + milliseconds(&run.solve),
+                warnings: &run.warnings,
+                profile: profile.then(|| Profile {
+                    parse: run.parse.into(),
+                    solve: run.solve.into(),
+                }),
+            };
+            let json = serde_json::to_string(&record).wrap_err("failed to write the answer")?;
+            println!("{json}");
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn test_record() {
+        let measurement = Measurement {
+            elapsed: Duration::from_micros(1500),
+            allocations: 3,
+            peak_bytes: 64,
+        };
+        let record = Record {
+            day: 10,
+            part: 2,
+            answer: "##..\n..##",
+            elapsed_ms: 3.0,
+            warnings: &["line 3 was skipped".to_string()],
+            profile: None,
+        };
+        assert_eq!(
+            serde_json::to_string(&record).unwrap(),
+            r###"{"day":10,"part":2,"answer":"##..\n..##","elapsed_ms":3.0,"warnings":["line 3 was skipped"]}"###
+        );
+        assert_eq!(
+            serde_json::to_string(&Phase::from(measurement)).unwrap(),
+            r#"{"elapsed_ms":1.5,"allocations":3,"peak_bytes":64}"#
+        );
+    }
+}
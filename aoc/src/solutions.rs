@@ -0,0 +1,54 @@
+use color_eyre::eyre::eyre;
+use common::{Mode, Solver};
+
+use crate::profile::{measure, Measurement};
+
+/// The answer of a part together with the cost of parsing and solving it.
+pub struct Run {
+    pub answer: String,
+    pub warnings: Vec<String>,
+    pub parse: Measurement,
+    pub solve: Measurement,
+}
+
+pub type Solution = fn(&str, u8, Mode) -> color_eyre::Result<Run>;
+
+pub fn find(day: u8) -> Option<Solution> {
+    let solution: Solution = match day {
+        1 => solve::<day1::Inventory>,
+        2 => solve::<day2::StrategyGuide>,
+        3 => solve::<day3::Rucksacks>,
+        4 => solve::<day4::CleanupPlan>,
+        5 => solve::<day5::Rearrangement>,
+        6 => solve::<day6::Datastream>,
+        7 => solve::<day7::FileSystem>,
+        8 => solve::<day8::Forest>,
+        9 => solve::<day9::Motions>,
+        10 => solve::<day10::Program>,
+        11 => solve::<day11::KeepAway>,
+        12 => solve::<day12::Map>,
+        13 => solve::<day13::DistressSignal>,
+        14 => solve::<day14::Scan>,
+        15 => solve::<day15::Map>,
+        16 => solve::<day16::Cave>,
+        17 => solve::<day17::Jets>,
+        _ => return None,
+    };
+    Some(solution)
+}
+
+fn solve<S: Solver>(input: &str, part: u8, mode: Mode) -> color_eyre::Result<Run> {
+    let (puzzle, parse) = measure(|| S::parse_with(input, mode));
+    let puzzle = puzzle?;
+    let (answer, solve) = measure(|| match part {
+        1 => Ok(puzzle.part1()?.to_string()),
+        2 => Ok(puzzle.part2()?.to_string()),
+        _ => Err(eyre!("part {part} does not exist")),
+    });
+    Ok(Run {
+        answer: answer?,
+        warnings: puzzle.warnings(),
+        parse,
+        solve,
+    })
+}
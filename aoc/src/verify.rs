@@ -0,0 +1,144 @@
+use std::{
+    fmt::Display,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use color_eyre::eyre::{eyre, WrapErr};
+use common::Mode;
+use serde::Deserialize;
+
+use crate::solutions;
+
+#[derive(Deserialize, Debug)]
+struct Answers {
+    #[serde(default)]
+    answer: Vec<Answer>,
+}
+
+/// A recorded answer, written as an `[[answer]]` table in the answers file.
+#[derive(Deserialize, Debug)]
+struct Answer {
+    day: u8,
+    part: u8,
+    /// Puzzle input, relative to the answers file
+    input: PathBuf,
+    expected: Expected,
+}
+
+/// Numbers may be written without quotes, everything else is compared as text.
+#[derive(Deserialize, Debug)]
+#[serde(untagged)]
+enum Expected {
+    Number(i64),
+    Text(String),
+}
+
+impl Display for Expected {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Expected::Number(number) => write!(f, "{number}"),
+            Expected::Text(text) => write!(f, "{text}"),
+        }
+    }
+}
+
+enum Outcome {
+    Pass,
+    Fail(String),
+    Error(color_eyre::Report),
+}
+
+fn check(answer: &Answer, directory: &Path) -> (Outcome, Duration) {
+    let start = Instant::now();
+    let result = solutions::find(answer.day)
+        .ok_or_else(|| eyre!("day {} is not solved", answer.day))
+        .and_then(|solution| {
+            let path = directory.join(&answer.input);
+            let input = std::fs::read_to_string(&path)
+                .wrap_err_with(|| format!("failed to read input {}", path.display()))?;
+            solution(&input, answer.part, Mode::Strict)
+        })
+        .map(|run| run.answer);
+    let elapsed = start.elapsed();
+    let outcome = match result {
+        Ok(actual) if actual == answer.expected.to_string() => Outcome::Pass,
+        Ok(actual) => Outcome::Fail(actual),
+        Err(error) => Outcome::Error(error),
+    };
+    (outcome, elapsed)
+}
+
+fn read_answers(path: &Path) -> color_eyre::Result<Answers> {
+    let text = std::fs::read_to_string(path)
+        .wrap_err_with(|| format!("failed to read answers {}", path.display()))?;
+    toml::from_str(&text).wrap_err_with(|| format!("failed to parse answers {}", path.display()))
+}
+
+/// Runs every answer of the answers file and prints one line per answer.
+///
+/// Fails when an answer does not match or its solver returns an error.
+pub fn run(path: &Path) -> color_eyre::Result<()> {
+    let answers = read_answers(path)?;
+    let directory = path.parent().unwrap_or(Path::new(""));
+    let mut failed = 0;
+    for answer in &answers.answer {
+        let (outcome, elapsed) = check(answer, directory);
+        let label = format!("day {:>2} part {}", answer.day, answer.part);
+        let time = format!("{:>10.3} ms", elapsed.as_secs_f64() * 1000.0);
+        match outcome {
+            Outcome::Pass => println!("{label}  pass  {time}"),
+            Outcome::Fail(actual) => {
+                failed += 1;
+                println!("{label}  FAIL  {time}");
+                println!("  expected: {}", answer.expected);
+                println!("  actual:   {actual}");
+            }
+            Outcome::Error(error) => {
+                failed += 1;
+                println!("{label}  ERROR {time}");
+                println!("  {error:#}");
+            }
+        }
+    }
+    let total = answers.answer.len();
+    println!("{} of {total} answers passed", total - failed);
+    match failed {
+        0 => Ok(()),
+        _ => Err(eyre!("{failed} of {total} answers did not pass")),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_recorded_answers() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("../answers.toml");
+        let answers = read_answers(&path).unwrap();
+        let directory = path.parent().unwrap();
+        for answer in &answers.answer {
+            match check(answer, directory).0 {
+                Outcome::Pass => {}
+                Outcome::Fail(actual) => panic!(
+                    "day {} part {}: expected {}, got {actual}",
+                    answer.day, answer.part, answer.expected
+                ),
+                Outcome::Error(error) => {
+                    panic!("day {} part {}: {error:#}", answer.day, answer.part)
+                }
+            }
+        }
+    }
+    #[test]
+    fn test_expected() {
+        let answers: Answers = toml::from_str(
+            "[[answer]]\nday = 5\npart = 1\ninput = \"x\"\nexpected = \"CMZ\"\n\
+             [[answer]]\nday = 1\npart = 1\ninput = \"x\"\nexpected = 24000\n",
+        )
+        .unwrap();
+        assert_eq!(answers.answer[0].expected.to_string(), "CMZ");
+        assert_eq!(answers.answer[1].expected.to_string(), "24000");
+    }
+}
@@ -0,0 +1,101 @@
+use std::{
+    ffi::OsString,
+    path::{Path, PathBuf},
+};
+
+use color_eyre::eyre::{eyre, WrapErr};
+
+/// Environment variable naming the directory with the inputs of every day.
+pub const INPUT_DIR_VARIABLE: &str = "AOC_INPUT_DIR";
+/// Directory searched for inputs when no other location is given.
+pub const DEFAULT_INPUT_DIR: &str = "inputs";
+
+/// Name of the input file of `day` inside of an input directory, e.g. `day07.txt`.
+pub fn file_name(day: u8) -> String {
+    format!("day{day:02}.txt")
+}
+
+/// Paths to look for the input of `day`, in order.
+///
+/// An explicit path is the only candidate, otherwise the input directory from
+/// the environment comes before the default one.
+fn candidates(day: u8, explicit: Option<&Path>, input_dir: Option<OsString>) -> Vec<PathBuf> {
+    if let Some(path) = explicit {
+        return vec![path.to_path_buf()];
+    }
+    input_dir
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .into_iter()
+        .chain([PathBuf::from(DEFAULT_INPUT_DIR)])
+        .map(|dir| dir.join(file_name(day)))
+        .collect()
+}
+
+fn find(candidates: Vec<PathBuf>, day: u8) -> color_eyre::Result<PathBuf> {
+    match candidates.iter().find(|path| path.is_file()) {
+        Some(path) => Ok(path.clone()),
+        None => Err(eyre!(
+            "no input for day {day} found, tried {}",
+            candidates
+                .iter()
+                .map(|path| path.display().to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )),
+    }
+}
+
+/// Finds the input of `day`: `explicit` if given, then `$AOC_INPUT_DIR/dayNN.txt`,
+/// then `inputs/dayNN.txt`.
+pub fn resolve(day: u8, explicit: Option<&Path>) -> color_eyre::Result<PathBuf> {
+    find(
+        candidates(day, explicit, std::env::var_os(INPUT_DIR_VARIABLE)),
+        day,
+    )
+}
+
+/// Reads the input of `day` from the first path found by [`resolve`].
+pub fn read(day: u8, explicit: Option<&Path>) -> color_eyre::Result<String> {
+    let path = resolve(day, explicit)?;
+    std::fs::read_to_string(&path)
+        .wrap_err_with(|| format!("failed to read input {}", path.display()))
+}
+
+/// Reads the input of `day`, taking the first command line argument as explicit path.
+pub fn from_args(day: u8) -> color_eyre::Result<String> {
+    let explicit = std::env::args_os().nth(1).map(PathBuf::from);
+    read(day, explicit.as_deref())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_candidates() {
+        assert_eq!(
+            candidates(7, Some(Path::new("my.txt")), Some("dir".into())),
+            vec![PathBuf::from("my.txt")]
+        );
+        assert_eq!(
+            candidates(7, None, Some("dir".into())),
+            vec![
+                PathBuf::from("dir/day07.txt"),
+                PathBuf::from("inputs/day07.txt")
+            ]
+        );
+        assert_eq!(
+            candidates(12, None, None),
+            vec![PathBuf::from("inputs/day12.txt")]
+        );
+    }
+    #[test]
+    fn test_not_found() {
+        let error = find(candidates(7, None, Some("missing".into())), 7).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "no input for day 7 found, tried missing/day07.txt, inputs/day07.txt"
+        );
+    }
+}
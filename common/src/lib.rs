@@ -0,0 +1,36 @@
+use std::fmt::Display;
+
+pub mod input;
+pub mod lines;
+pub mod parse;
+
+pub use lines::RejectedLine;
+
+/// How malformed lines of an input are handled.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Mode {
+    /// The first malformed line fails the parse.
+    #[default]
+    Strict,
+    /// Malformed lines are skipped and reported as warnings.
+    Lenient,
+}
+
+/// A puzzle parsed from its input which can answer both of its parts.
+pub trait Solver: Sized {
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(input: &str) -> color_eyre::Result<Self>;
+    /// Parses in the given mode, for solvers which can skip malformed lines.
+    fn parse_with(input: &str, _mode: Mode) -> color_eyre::Result<Self> {
+        Self::parse(input)
+    }
+    fn part1(&self) -> color_eyre::Result<Self::Part1>;
+    fn part2(&self) -> color_eyre::Result<Self::Part2>;
+
+    /// Problems with the input that the solver worked around instead of failing.
+    fn warnings(&self) -> Vec<String> {
+        Vec::new()
+    }
+}
@@ -0,0 +1,112 @@
+use std::fmt::{self, Display, Formatter};
+
+use color_eyre::eyre::WrapErr;
+
+use crate::Mode;
+
+/// A malformed line of an input, skipped in lenient mode.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RejectedLine {
+    /// Number of the line, starting at 1
+    pub line: usize,
+    pub text: String,
+    /// The error of the line with all of its causes
+    pub reason: String,
+}
+
+/// The warning for the line, like ``line 2: `2-x` skipped (`x` is no section)``.
+impl Display for RejectedLine {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}: `{}` skipped ({})",
+            self.line,
+            self.text.escape_debug(),
+            self.reason
+        )
+    }
+}
+
+/// Handles `error` of line number `line` according to `mode`.
+///
+/// Strict mode fails with the error and the line number, lenient mode adds the line to
+/// `rejected` and carries on.
+pub fn reject(
+    mode: Mode,
+    line: usize,
+    text: &str,
+    error: color_eyre::Report,
+    rejected: &mut Vec<RejectedLine>,
+) -> color_eyre::Result<()> {
+    match mode {
+        Mode::Strict => Err(error).wrap_err_with(|| format!("line {line}")),
+        Mode::Lenient => {
+            rejected.push(RejectedLine {
+                line,
+                text: text.to_string(),
+                reason: format!("{error:#}"),
+            });
+            Ok(())
+        }
+    }
+}
+
+/// Parses every non-empty line of `input` with `parse`, which also gets the line number.
+///
+/// Malformed lines are handled by [`reject`] and returned next to the parsed ones.
+pub fn parse<T>(
+    input: &str,
+    mode: Mode,
+    mut parse: impl FnMut(&str, usize) -> color_eyre::Result<T>,
+) -> color_eyre::Result<(Vec<T>, Vec<RejectedLine>)> {
+    let mut parsed = vec![];
+    let mut rejected = vec![];
+    for (index, text) in input.lines().enumerate() {
+        if text.is_empty() {
+            continue;
+        }
+        match parse(text, index + 1) {
+            Ok(value) => parsed.push(value),
+            Err(error) => reject(mode, index + 1, text, error, &mut rejected)?,
+        }
+    }
+    Ok((parsed, rejected))
+}
+
+#[cfg(test)]
+mod test {
+    use color_eyre::eyre::eyre;
+
+    use super::*;
+
+    fn number(text: &str, _line: usize) -> color_eyre::Result<u32> {
+        text.parse().wrap_err("not a number")
+    }
+
+    #[test]
+    fn test_parse() {
+        let input = "1\nx\n\n3\ny\tz\n";
+        assert_eq!(
+            format!("{:#}", parse(input, Mode::Strict, number).unwrap_err()),
+            "line 2: not a number: invalid digit found in string"
+        );
+        let (numbers, rejected) = parse(input, Mode::Lenient, number).unwrap();
+        assert_eq!(numbers, vec![1, 3]);
+        assert_eq!(
+            rejected.iter().map(|r| r.line).collect::<Vec<_>>(),
+            vec![2, 5]
+        );
+        assert_eq!(
+            rejected[1].to_string(),
+            "line 5: `y\\tz` skipped (not a number: invalid digit found in string)"
+        );
+    }
+    #[test]
+    fn test_reject() {
+        let mut rejected = vec![];
+        reject(Mode::Lenient, 4, "a", eyre!("bad"), &mut rejected).unwrap();
+        assert_eq!(rejected[0].reason, "bad");
+        assert!(reject(Mode::Strict, 4, "a", eyre!("bad"), &mut rejected).is_err());
+        assert_eq!(rejected.len(), 1);
+    }
+}
@@ -0,0 +1,259 @@
+//! Errors for the nom parsers of the puzzle inputs that point at the offending line.
+
+use std::fmt::{self, Display, Formatter};
+
+use nom::error::{ContextError, ErrorKind, FromExternalError};
+
+/// Result of a parser over puzzle input, failing with an [`Error`].
+pub type IResult<I, O> = nom::IResult<I, O, Error<I>>;
+
+/// What a parser was looking for when it failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Expected {
+    Context(&'static str),
+    Char(char),
+    Kind(ErrorKind),
+}
+
+impl Expected {
+    /// Whether this already tells the reader what was expected, without a context.
+    fn is_descriptive(&self) -> bool {
+        !matches!(
+            self,
+            Expected::Kind(
+                ErrorKind::Tag
+                    | ErrorKind::Alt
+                    | ErrorKind::Many0
+                    | ErrorKind::Many1
+                    | ErrorKind::SeparatedList
+                    | ErrorKind::TakeUntil
+                    | ErrorKind::MapRes
+                    | ErrorKind::Verify
+            )
+        )
+    }
+}
+
+impl Display for Expected {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Expected::Context(context) => write!(f, "{context}"),
+            Expected::Char(c) => write!(f, "`{c}`"),
+            Expected::Kind(ErrorKind::Digit) => write!(f, "a number"),
+            Expected::Kind(ErrorKind::Alpha) => write!(f, "a letter"),
+            Expected::Kind(ErrorKind::CrLf) => write!(f, "end of line"),
+            Expected::Kind(ErrorKind::Eof) => write!(f, "end of input"),
+            Expected::Kind(ErrorKind::Space | ErrorKind::MultiSpace) => write!(f, "whitespace"),
+            Expected::Kind(kind) => write!(f, "{}", kind.description().to_lowercase()),
+        }
+    }
+}
+
+/// Error of a single parser, holding the input left at the point of failure.
+///
+/// Wrapping a parser in [`nom::error::context`] names what it expects. Errors which
+/// already say what they expected, like a missing number or character, keep their own
+/// description, so the most specific one is reported.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error<I> {
+    input: I,
+    expected: Expected,
+}
+
+impl<I> nom::error::ParseError<I> for Error<I> {
+    fn from_error_kind(input: I, kind: ErrorKind) -> Self {
+        Error {
+            input,
+            expected: Expected::Kind(kind),
+        }
+    }
+
+    fn append(_input: I, _kind: ErrorKind, other: Self) -> Self {
+        other
+    }
+
+    fn from_char(input: I, c: char) -> Self {
+        Error {
+            input,
+            expected: Expected::Char(c),
+        }
+    }
+
+    fn or(self, other: Self) -> Self {
+        // None of the alternatives matched, so neither of their errors is the whole story.
+        Error {
+            input: other.input,
+            expected: Expected::Kind(ErrorKind::Alt),
+        }
+    }
+}
+
+impl<I> ContextError<I> for Error<I> {
+    fn add_context(_input: I, context: &'static str, mut other: Self) -> Self {
+        if !other.expected.is_descriptive() {
+            other.expected = Expected::Context(context);
+        }
+        other
+    }
+}
+
+impl<I, E> FromExternalError<I, E> for Error<I> {
+    fn from_external_error(input: I, kind: ErrorKind, _e: E) -> Self {
+        nom::error::ParseError::from_error_kind(input, kind)
+    }
+}
+
+/// A parse failure located in the puzzle input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Line of the failure, starting at 1.
+    pub line: usize,
+    /// Column of the failure within its line, starting at 1.
+    pub column: usize,
+    /// Rest of the line from the column of the failure.
+    pub text: String,
+    /// What the parser was looking for, if known.
+    pub expected: Option<String>,
+}
+
+impl ParseError {
+    fn new(input: &str, rest: &str, expected: Option<String>) -> Self {
+        let consumed = &input[..input.len() - rest.len()];
+        let line_start = consumed.rfind('\n').map_or(0, |i| i + 1);
+        ParseError {
+            line: consumed.matches('\n').count() + 1,
+            column: consumed[line_start..].chars().count() + 1,
+            text: rest.lines().next().unwrap_or_default().to_string(),
+            expected,
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}: ", self.line, self.column)?;
+        let found = if self.text.is_empty() {
+            "end of line".to_string()
+        } else {
+            format!("`{}`", self.text)
+        };
+        match &self.expected {
+            Some(expected) => write!(f, "expected {expected}, found {found}"),
+            None => write!(f, "unexpected {found}"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Applies `parser` until only whitespace is left, failing with the error of the first
+/// item it cannot read instead of stopping silently like [`nom::multi::many0`].
+pub fn many_to_end<'a, O>(
+    mut parser: impl FnMut(&'a str) -> IResult<&'a str, O>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>> {
+    move |mut input: &'a str| {
+        let mut items = vec![];
+        while !input.trim_start().is_empty() {
+            let (rest, item) = parser(input)?;
+            if rest.len() == input.len() {
+                return Err(nom::Err::Error(nom::error::ParseError::from_error_kind(
+                    input,
+                    ErrorKind::Many0,
+                )));
+            }
+            items.push(item);
+            input = rest;
+        }
+        Ok((input, items))
+    }
+}
+
+/// Runs `parser` over the whole `input`, of which only trailing whitespace may be left.
+pub fn finish<'a, O>(
+    input: &'a str,
+    mut parser: impl FnMut(&'a str) -> IResult<&'a str, O>,
+) -> Result<O, ParseError> {
+    match parser(input) {
+        Ok((rest, output)) => {
+            let rest = rest.trim_start();
+            if rest.is_empty() {
+                Ok(output)
+            } else {
+                Err(ParseError::new(input, rest, None))
+            }
+        }
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) => Err(ParseError::new(
+            input,
+            e.input,
+            Some(e.expected.to_string()),
+        )),
+        Err(nom::Err::Incomplete(_)) => {
+            Err(ParseError::new(input, "", Some("more input".to_string())))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use nom::{
+        bytes::complete::tag,
+        character::complete::{line_ending, u32},
+        combinator::cut,
+        error::context,
+        sequence::{preceded, terminated},
+    };
+
+    use super::*;
+
+    fn parse_throw(input: &str) -> IResult<&str, u32> {
+        terminated(
+            preceded(
+                tag("If true: "),
+                context(
+                    "`throw to monkey <n>`",
+                    cut(preceded(tag("throw to monkey "), u32)),
+                ),
+            ),
+            line_ending,
+        )(input)
+    }
+
+    #[test]
+    fn test_finish() {
+        assert_eq!(finish("If true: throw to monkey 2\n", parse_throw), Ok(2));
+    }
+    #[test]
+    fn test_error_location() {
+        let error = finish(
+            "If true: throw to monkey 2\nIf true: throw to mnkey 2\n",
+            |i| many_to_end(parse_throw)(i),
+        )
+        .unwrap_err();
+        assert_eq!(
+            error,
+            ParseError {
+                line: 2,
+                column: 10,
+                text: "throw to mnkey 2".to_string(),
+                expected: Some("`throw to monkey <n>`".to_string()),
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 10: expected `throw to monkey <n>`, found `throw to mnkey 2`"
+        );
+    }
+    #[test]
+    fn test_expected_number() {
+        let error = finish("If true: throw to monkey two\n", parse_throw).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 26: expected a number, found `two`"
+        );
+    }
+    #[test]
+    fn test_unexpected_rest() {
+        let error = finish("If true: throw to monkey 2\n\n  trailing", parse_throw).unwrap_err();
+        assert_eq!(error.to_string(), "line 3, column 3: unexpected `trailing`");
+    }
+}
@@ -0,0 +1,365 @@
+use std::{
+    cmp::Reverse,
+    collections::{BTreeMap, BinaryHeap},
+    io::{BufRead, Lines},
+};
+
+use color_eyre::eyre::{eyre, OptionExt, WrapErr};
+use common::{lines, Mode, RejectedLine, Solver};
+
+mod report;
+
+pub use report::Report;
+
+/// An elf with the calories of every food item it carries.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Elf {
+    /// Position of the elf in the inventory, starting at 0
+    pub index: usize,
+    pub items: Vec<u64>,
+}
+
+impl Elf {
+    pub fn total(&self) -> u64 {
+        self.items.iter().sum()
+    }
+    pub fn largest_item(&self) -> Option<u64> {
+        self.items.iter().copied().max()
+    }
+    pub fn summary(&self) -> ElfTotal {
+        ElfTotal {
+            index: self.index,
+            total: self.total(),
+            items: self.items.len(),
+        }
+    }
+}
+
+/// The calories carried by one elf.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ElfTotal {
+    /// Position of the elf in the inventory, starting at 0
+    pub index: usize,
+    pub total: u64,
+    /// Number of food items the elf carries
+    pub items: usize,
+}
+
+/// The elves of an inventory, read one line at a time.
+///
+/// Elves are separated by empty lines, every other line holds the calories of one item.
+pub struct Elves<R> {
+    lines: Lines<R>,
+    mode: Mode,
+    index: usize,
+    line: usize,
+    rejected: Vec<RejectedLine>,
+}
+
+impl<R: BufRead> Elves<R> {
+    pub fn new(reader: R) -> Self {
+        Self::with_mode(reader, Mode::Strict)
+    }
+    pub fn with_mode(reader: R, mode: Mode) -> Self {
+        Elves {
+            lines: reader.lines(),
+            mode,
+            index: 0,
+            line: 0,
+            rejected: vec![],
+        }
+    }
+    /// Malformed lines skipped so far in lenient mode.
+    pub fn rejected(&self) -> &[RejectedLine] {
+        &self.rejected
+    }
+}
+
+impl<R: BufRead> Iterator for Elves<R> {
+    type Item = color_eyre::Result<Elf>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut elf: Option<Elf> = None;
+        let mut total: u64 = 0;
+        while let Some(line) = self.lines.next() {
+            self.line += 1;
+            let line = match line {
+                Ok(line) => line,
+                Err(error) => {
+                    return Some(
+                        Err(error).wrap_err_with(|| format!("failed to read line {}", self.line)),
+                    )
+                }
+            };
+            let line = line.trim();
+            if line.is_empty() {
+                match elf {
+                    Some(_) => break,
+                    None => continue,
+                }
+            }
+            match line.parse::<u64>() {
+                Ok(calories) => match total.checked_add(calories) {
+                    Some(sum) => {
+                        total = sum;
+                        elf.get_or_insert_with(|| Elf {
+                            index: self.index,
+                            items: vec![],
+                        })
+                        .items
+                        .push(calories);
+                    }
+                    None => {
+                        return Some(Err(eyre!(
+                            "line {}: the calories of elf {} do not fit in 64 bits",
+                            self.line,
+                            self.index + 1
+                        )))
+                    }
+                },
+                Err(error) => {
+                    let error = eyre!(error).wrap_err("not a number of calories");
+                    if let Err(error) =
+                        lines::reject(self.mode, self.line, line, error, &mut self.rejected)
+                    {
+                        return Some(Err(error));
+                    }
+                }
+            }
+        }
+        if elf.is_some() {
+            self.index += 1;
+        }
+        elf.map(Ok)
+    }
+}
+
+/// The `n` elves carrying the most calories, best first, with earlier elves winning ties.
+///
+/// Only `n` elves are kept at any time, so `elves` may be of any length.
+pub fn rank(elves: impl IntoIterator<Item = ElfTotal>, n: usize) -> Vec<ElfTotal> {
+    if n == 0 {
+        return vec![];
+    }
+    let mut best = BinaryHeap::new();
+    for elf in elves {
+        best.push(Reverse((elf.total, Reverse(elf.index), elf.items)));
+        if best.len() > n {
+            best.pop();
+        }
+    }
+    best.into_sorted_vec()
+        .into_iter()
+        .map(|Reverse((total, Reverse(index), items))| ElfTotal {
+            index,
+            total,
+            items,
+        })
+        .collect()
+}
+
+/// The `n` elves carrying the most calories in the inventory read from `reader`.
+pub fn top_n(reader: impl BufRead, n: usize) -> color_eyre::Result<Vec<ElfTotal>> {
+    let mut error = None;
+    let elves = Elves::new(reader)
+        .map_while(|elf| elf.map_err(|e| error = Some(e)).ok())
+        .map(|elf| elf.summary());
+    let ranked = rank(elves, n);
+    match error {
+        Some(error) => Err(error),
+        None => Ok(ranked),
+    }
+}
+
+/// Calories carried by every elf, in the order of the input.
+pub struct Inventory {
+    elves: Vec<Elf>,
+    rejected: Vec<RejectedLine>,
+}
+
+impl Inventory {
+    pub fn elves(&self) -> &[Elf] {
+        &self.elves
+    }
+    fn totals(&self) -> impl Iterator<Item = ElfTotal> + '_ {
+        self.elves.iter().map(Elf::summary)
+    }
+    /// The food item with the most calories and the elf carrying it, the first one on ties.
+    pub fn largest_item(&self) -> Option<(&Elf, u64)> {
+        self.elves
+            .iter()
+            .filter_map(|elf| Some((elf, elf.largest_item()?)))
+            .rev()
+            .max_by_key(|(_, calories)| *calories)
+    }
+    /// Mean of the calories carried per elf.
+    pub fn mean_total(&self) -> Option<f64> {
+        if self.elves.is_empty() {
+            return None;
+        }
+        let sum: u128 = self.elves.iter().map(|elf| elf.total() as u128).sum();
+        Some(sum as f64 / self.elves.len() as f64)
+    }
+    /// Median of the calories carried per elf, the mean of the middle two for an even number of elves.
+    pub fn median_total(&self) -> Option<f64> {
+        let mut totals: Vec<u64> = self.elves.iter().map(Elf::total).collect();
+        totals.sort_unstable();
+        let middle = totals.len() / 2;
+        match totals.len() {
+            0 => None,
+            n if n % 2 == 1 => Some(totals[middle] as f64),
+            _ => Some((totals[middle - 1] as f64 + totals[middle] as f64) / 2.0),
+        }
+    }
+    /// Number of elves per range of `bucket_size` calories, keyed by the start of the range.
+    ///
+    /// Ranges without elves are left out.
+    pub fn histogram(&self, bucket_size: u64) -> BTreeMap<u64, usize> {
+        assert!(bucket_size > 0, "the buckets need to hold some calories");
+        let mut histogram = BTreeMap::new();
+        for elf in &self.elves {
+            let bucket = elf.total() / bucket_size * bucket_size;
+            *histogram.entry(bucket).or_default() += 1;
+        }
+        histogram
+    }
+    /// Elves carrying more than `threshold` calories.
+    pub fn above(&self, threshold: u64) -> impl Iterator<Item = &Elf> {
+        self.elves.iter().filter(move |elf| elf.total() > threshold)
+    }
+}
+
+impl Solver for Inventory {
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> color_eyre::Result<Self> {
+        Self::parse_with(input, Mode::Strict)
+    }
+
+    fn parse_with(input: &str, mode: Mode) -> color_eyre::Result<Self> {
+        let mut elves = Elves::with_mode(input.as_bytes(), mode);
+        let list = elves
+            .by_ref()
+            .collect::<color_eyre::Result<_>>()
+            .wrap_err("failed to parse the inventory")?;
+        Ok(Inventory {
+            elves: list,
+            rejected: elves.rejected,
+        })
+    }
+
+    fn part1(&self) -> color_eyre::Result<u64> {
+        rank(self.totals(), 1)
+            .first()
+            .map(|elf| elf.total)
+            .ok_or_eyre("there are no elves")
+    }
+
+    fn part2(&self) -> color_eyre::Result<u64> {
+        rank(self.totals(), 3)
+            .iter()
+            .try_fold(0u64, |sum, elf| sum.checked_add(elf.total))
+            .ok_or_eyre("the calories of the top three elves do not fit in 64 bits")
+    }
+
+    fn warnings(&self) -> Vec<String> {
+        self.rejected.iter().map(RejectedLine::to_string).collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+
+    #[test]
+    fn test_top_n() {
+        let top = top_n(EXAMPLE.as_bytes(), 3).unwrap();
+        assert_eq!(
+            top,
+            vec![
+                ElfTotal {
+                    index: 3,
+                    total: 24000,
+                    items: 3
+                },
+                ElfTotal {
+                    index: 2,
+                    total: 11000,
+                    items: 2
+                },
+                ElfTotal {
+                    index: 4,
+                    total: 10000,
+                    items: 1
+                },
+            ]
+        );
+        assert_eq!(top_n(EXAMPLE.as_bytes(), 100).unwrap().len(), 5);
+        assert!(top_n(EXAMPLE.as_bytes(), 0).unwrap().is_empty());
+        assert_eq!(top_n(EXAMPLE.as_bytes(), 1 << 40).unwrap().len(), 5);
+        assert_eq!(top_n(EXAMPLE.as_bytes(), usize::MAX).unwrap().len(), 5);
+    }
+    #[test]
+    fn test_statistics() {
+        let inventory = Inventory::parse(EXAMPLE).unwrap();
+        let (elf, calories) = inventory.largest_item().unwrap();
+        assert_eq!((elf.index, calories), (4, 10000));
+        assert_eq!(inventory.mean_total(), Some(11000.0));
+        assert_eq!(inventory.median_total(), Some(10000.0));
+        assert_eq!(
+            inventory.histogram(10000),
+            BTreeMap::from([(0, 2), (10000, 2), (20000, 1)])
+        );
+        assert_eq!(
+            inventory
+                .above(10000)
+                .map(|elf| elf.index)
+                .collect::<Vec<_>>(),
+            vec![2, 3]
+        );
+    }
+    #[test]
+    fn test_malformed_lines() {
+        let input = "1000\n12a4\n\n\n2000\n";
+        assert_eq!(
+            format!("{:#}", Inventory::parse(input).err().unwrap()),
+            "failed to parse the inventory: line 2: not a number of calories: \
+             invalid digit found in string"
+        );
+        let inventory = Inventory::parse_with(input, Mode::Lenient).unwrap();
+        assert_eq!(
+            inventory.elves().iter().map(Elf::total).collect::<Vec<_>>(),
+            vec![1000, 2000]
+        );
+        assert_eq!(inventory.warnings().len(), 1);
+        // A group of malformed lines only is no elf.
+        let inventory = Inventory::parse_with("1000\n\nabc\nx1\n\n2000\n", Mode::Lenient).unwrap();
+        assert_eq!(
+            inventory
+                .elves()
+                .iter()
+                .map(|elf| (elf.index, elf.total()))
+                .collect::<Vec<_>>(),
+            vec![(0, 1000), (1, 2000)]
+        );
+        assert_eq!(inventory.part2().unwrap(), 3000);
+        assert_eq!(inventory.warnings().len(), 2);
+    }
+    #[test]
+    fn test_overflow() {
+        let input = format!("{}\n1\n", u64::MAX);
+        assert!(format!("{:#}", Inventory::parse(&input).err().unwrap())
+            .ends_with("line 2: the calories of elf 1 do not fit in 64 bits"));
+    }
+    #[test]
+    fn test_ties() {
+        let top = top_n("5\n\n7\n\n5\n".as_bytes(), 2).unwrap();
+        assert_eq!(
+            top.iter().map(|elf| elf.index).collect::<Vec<_>>(),
+            vec![1, 0]
+        );
+    }
+}
@@ -0,0 +1,59 @@
+use std::fmt::{self, Display, Formatter};
+
+use crate::Inventory;
+
+/// Statistics of an inventory for people to read, elves are numbered from 1.
+pub struct Report<'a> {
+    inventory: &'a Inventory,
+    bucket_size: u64,
+}
+
+impl<'a> Report<'a> {
+    /// Groups the totals of the histogram in ranges of `bucket_size` calories.
+    pub fn new(inventory: &'a Inventory, bucket_size: u64) -> Self {
+        Report {
+            inventory,
+            bucket_size,
+        }
+    }
+}
+
+impl Display for Report<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let elves = self.inventory.elves();
+        let items: usize = elves.iter().map(|elf| elf.items.len()).sum();
+        writeln!(f, "elves: {}, food items: {items}", elves.len())?;
+        if let Some((elf, calories)) = self.inventory.largest_item() {
+            writeln!(
+                f,
+                "largest item: {calories} calories, carried by elf {}",
+                elf.index + 1
+            )?;
+        }
+        let (Some(mean), Some(median)) =
+            (self.inventory.mean_total(), self.inventory.median_total())
+        else {
+            return Ok(());
+        };
+        writeln!(f, "calories per elf: mean {mean:.1}, median {median:.1}")?;
+        writeln!(
+            f,
+            "elves above the mean: {}",
+            self.inventory.above(mean as u64).count()
+        )?;
+        writeln!(f, "elves per range of calories:")?;
+        let histogram = self.inventory.histogram(self.bucket_size);
+        let width = histogram.keys().last().map_or(1, |last| {
+            last.saturating_add(self.bucket_size - 1).to_string().len()
+        });
+        for (start, count) in histogram {
+            let end = start.saturating_add(self.bucket_size - 1);
+            writeln!(
+                f,
+                "  {start:>width$}..={end:<width$} {count:>5} {}",
+                "#".repeat(count.min(60))
+            )?;
+        }
+        Ok(())
+    }
+}
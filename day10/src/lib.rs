@@ -0,0 +1,133 @@
+use color_eyre::eyre::WrapErr;
+use common::{
+    parse::{self, IResult},
+    Solver,
+};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::line_ending,
+    combinator::{cut, eof, map},
+    error::context,
+    sequence::{preceded, tuple},
+};
+
+#[derive(Debug)]
+pub enum Command {
+    Add(i32),
+    Noop,
+}
+
+pub struct Cpu {
+    register: i32,
+    program_counter: usize,
+    screen: String,
+    signal_strength: i32,
+}
+impl Cpu {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Cpu {
+            register: 1,
+            program_counter: 0,
+            screen: String::new(),
+            signal_strength: 0,
+        }
+    }
+    pub fn execute_command(&mut self, command: &Command) {
+        match command {
+            Command::Add(number) => {
+                self.increase_counter(2);
+                self.register += number;
+            }
+            Command::Noop => self.increase_counter(1),
+        }
+    }
+    fn increase_counter(&mut self, amount: usize) {
+        (1..=amount).for_each(|_| {
+            self.draw();
+            self.program_counter += 1;
+            if self.program_counter % 40 == 20 {
+                self.signal_strength += self.program_counter as i32 * self.register;
+            }
+        });
+    }
+    fn draw(&mut self) {
+        let position = self.program_counter % 40;
+        if position == 0 && self.program_counter != 0 {
+            self.screen.push('\n');
+        }
+        if position as i32 >= self.register - 1 && position as i32 <= self.register + 1 {
+            self.screen.push('#');
+        } else {
+            self.screen.push('.');
+        }
+    }
+    pub fn screen(&self) -> &str {
+        &self.screen
+    }
+    /// Sum of the signal strengths during the 20th, 60th, 100th, ... cycle.
+    pub fn signal_strength(&self) -> i32 {
+        self.signal_strength
+    }
+}
+
+pub struct Program {
+    commands: Vec<Command>,
+}
+
+impl Solver for Program {
+    type Part1 = i32;
+    type Part2 = String;
+
+    fn parse(input: &str) -> color_eyre::Result<Self> {
+        let commands =
+            parse::finish(input, parse_commands).wrap_err("failed to parse the program")?;
+        Ok(Program { commands })
+    }
+
+    fn part1(&self) -> color_eyre::Result<i32> {
+        Ok(self.run().signal_strength)
+    }
+
+    fn part2(&self) -> color_eyre::Result<String> {
+        Ok(self.run().screen)
+    }
+}
+
+impl Program {
+    fn run(&self) -> Cpu {
+        let mut cpu = Cpu::new();
+        self.commands.iter().for_each(|command| {
+            cpu.execute_command(command);
+        });
+        cpu
+    }
+}
+
+fn parse_commands(input: &str) -> IResult<&str, Vec<Command>> {
+    let (input, commands) = parse::many_to_end(parse_command)(input)?;
+    Ok((input, commands))
+}
+
+fn parse_command(input: &str) -> IResult<&str, Command> {
+    let (input, (command, _)) = tuple((
+        context("`addx <n>` or `noop`", alt((parse_add, parse_noop))),
+        context("end of line", cut(alt((line_ending, eof)))),
+    ))(input)?;
+    Ok((input, command))
+}
+fn parse_add(i: &str) -> IResult<&str, Command> {
+    map(
+        preceded(
+            tag("addx "),
+            context("a number", cut(nom::character::complete::i32)),
+        ),
+        Command::Add,
+    )(i)
+}
+
+fn parse_noop(input: &str) -> IResult<&str, Command> {
+    let (input, _) = tag("noop")(input)?;
+    Ok((input, Command::Noop))
+}
@@ -0,0 +1,244 @@
+use color_eyre::eyre::{eyre, WrapErr};
+use common::{
+    parse::{self, IResult},
+    Solver,
+};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{char, line_ending, space0, space1, u64},
+    combinator::{cut, map, opt},
+    error::context,
+    multi::many1,
+    sequence::{delimited, preceded, terminated, tuple},
+};
+#[derive(Debug, Clone)]
+enum Value {
+    Old,
+    Number(u64),
+}
+
+impl Value {
+    fn parse(input: &str) -> IResult<&str, Value> {
+        let (input, value) = alt((
+            map(tag("old"), |_| Value::Old),
+            map(u64, |n: u64| Value::Number(n)),
+        ))(input)?;
+        Ok((input, value))
+    }
+}
+#[derive(Debug, Clone)]
+struct Operation {
+    operation: char,
+    value: Value,
+}
+
+impl Operation {
+    fn parse(input: &str) -> IResult<&str, Operation> {
+        map(
+            tuple((
+                delimited(space0, alt((char('+'), char('*'))), space0),
+                Value::parse,
+            )),
+            |(operation, value)| Operation { operation, value },
+        )(input)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    items: Vec<u64>,
+    operation: Operation,
+    devisable: u64,
+    throw: (u64, u64),
+    inspections: u64,
+}
+
+impl Monkey {
+    fn parse(input: &str) -> IResult<&str, Monkey> {
+        let (input, _) = parse_monkey_number(input)?;
+        let (input, (items, operation, devisable, throw)) = cut(tuple((
+            parse_starting_items,
+            parse_operation,
+            parse_devisible,
+            parse_throw,
+        )))(input)?;
+        Ok((
+            input,
+            Monkey {
+                items,
+                operation,
+                devisable,
+                throw,
+                inspections: 0,
+            },
+        ))
+    }
+    fn operation(&mut self, number: u64) -> u64 {
+        match self.operation.operation {
+            '+' => match self.operation.value {
+                Value::Old => number + number,
+                Value::Number(n) => number + n,
+            },
+            '*' => match self.operation.value {
+                Value::Old => number * number,
+                Value::Number(n) => number * n,
+            },
+            _ => unreachable!(),
+        }
+    }
+    /// Inspects every held item, lowering the worry level with `relief` afterwards.
+    fn process_items(&mut self, relief: &dyn Fn(u64) -> u64) -> Vec<(usize, u64)> {
+        self.inspections += self.items.len() as u64;
+        let mut items = vec![];
+        let old_items = std::mem::take(&mut self.items);
+        old_items.iter().for_each(|number| {
+            let new_number = relief(self.operation(*number));
+            if new_number.is_multiple_of(self.devisable) {
+                items.push((self.throw.0 as usize, new_number));
+            } else {
+                items.push((self.throw.1 as usize, new_number));
+            }
+        });
+        items
+    }
+    pub fn inspections(&self) -> u64 {
+        self.inspections
+    }
+}
+
+pub struct KeepAway {
+    monkeys: Vec<Monkey>,
+}
+
+impl KeepAway {
+    pub fn monkeys(&self) -> &[Monkey] {
+        &self.monkeys
+    }
+    /// Product of the inspections of the two most active monkeys after `rounds`.
+    fn monkey_business(&self, rounds: usize, relief: &dyn Fn(u64) -> u64) -> u128 {
+        let mut monkeys = self.monkeys.clone();
+        (0..rounds).for_each(|_| {
+            (0..monkeys.len()).for_each(|i| {
+                monkeys[i]
+                    .process_items(relief)
+                    .iter()
+                    .for_each(|(monkey_num, number)| {
+                        monkeys[*monkey_num].items.push(*number);
+                    });
+            });
+        });
+        monkeys.sort_by_key(|a| a.inspections);
+        monkeys
+            .iter()
+            .rev()
+            .take(2)
+            .map(|m| m.inspections as u128)
+            .product()
+    }
+}
+
+impl Solver for KeepAway {
+    type Part1 = u128;
+    type Part2 = u128;
+
+    fn parse(input: &str) -> color_eyre::Result<Self> {
+        let monkeys =
+            parse::finish(input, parse_monkeys).wrap_err("failed to parse the monkeys")?;
+        for (i, monkey) in monkeys.iter().enumerate() {
+            if monkey.devisable == 0 {
+                return Err(eyre!("monkey {i} tests for divisibility by 0"));
+            }
+            for target in [monkey.throw.0, monkey.throw.1] {
+                if target as usize >= monkeys.len() || target as usize == i {
+                    return Err(eyre!("monkey {i} cannot throw to monkey {target}"));
+                }
+            }
+        }
+        Ok(KeepAway { monkeys })
+    }
+
+    fn part1(&self) -> color_eyre::Result<u128> {
+        Ok(self.monkey_business(20, &|number| number / 3))
+    }
+
+    fn part2(&self) -> color_eyre::Result<u128> {
+        // Keeping worry levels modulo the product of all divisors preserves every test.
+        let divisor_product = self.monkeys.iter().map(|m| m.devisable).product::<u64>();
+        Ok(self.monkey_business(10_000, &|number| number % divisor_product))
+    }
+}
+
+fn parse_monkeys(input: &str) -> IResult<&str, Vec<Monkey>> {
+    parse::many_to_end(terminated(Monkey::parse, opt(line_ending)))(input)
+}
+
+fn parse_monkey_number(input: &str) -> IResult<&str, u64> {
+    context(
+        "`Monkey <n>:`",
+        delimited(tag("Monkey "), u64, tuple((char(':'), line_ending))),
+    )(input)
+}
+fn parse_list_item(input: &str) -> IResult<&str, u64> {
+    preceded(space0, u64)(input)
+}
+fn parse_starting_items(input: &str) -> IResult<&str, Vec<u64>> {
+    let (input, numbers) = context(
+        "`Starting items: <n>, <n>, ...`",
+        preceded(
+            tuple((space1, tag("Starting items:"))),
+            terminated(many1(tuple((parse_list_item, opt(char(','))))), tag("\n")),
+        ),
+    )(input)?;
+    Ok((input, numbers.into_iter().map(|(n, _)| n).collect()))
+}
+fn parse_operation(input: &str) -> IResult<&str, Operation> {
+    let (input, operation) = context(
+        "`Operation: new = old <+ or *> <n or old>`",
+        preceded(
+            tuple((space1, tag("Operation: new = old"))),
+            terminated(Operation::parse, tag("\n")),
+        ),
+    )(input)?;
+    Ok((input, operation))
+}
+
+fn parse_devisible(input: &str) -> IResult<&str, u64> {
+    context(
+        "`Test: divisible by <n>`",
+        delimited(
+            tuple((space1, tag("Test: divisible by "))),
+            u64,
+            line_ending,
+        ),
+    )(input)
+}
+fn parse_throw_line(input: &str) -> IResult<&str, (bool, u64)> {
+    tuple((
+        context(
+            "`If <true or false>: `",
+            map(
+                delimited(
+                    tuple((space1, tag("If "))),
+                    alt((tag("true"), tag("false"))),
+                    tag(": "),
+                ),
+                |s| match s {
+                    "true" => true,
+                    "false" => false,
+                    _ => unreachable!(),
+                },
+            ),
+        ),
+        context(
+            "`throw to monkey <n>`",
+            delimited(tag("throw to monkey "), u64, line_ending),
+        ),
+    ))(input)
+}
+
+fn parse_throw(input: &str) -> IResult<&str, (u64, u64)> {
+    let (input, (_, first)) = parse_throw_line(input)?;
+    let (input, (_, second)) = parse_throw_line(input)?;
+    Ok((input, (first, second)))
+}
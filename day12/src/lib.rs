@@ -0,0 +1,125 @@
+use std::collections::{HashSet, VecDeque};
+
+use color_eyre::eyre::{eyre, OptionExt};
+use common::Solver;
+use geometry::{Grid, Point};
+
+mod visualization;
+
+pub use visualization::{start, Exploration};
+
+type Coordinate = Point<usize>;
+
+pub struct Map {
+    map: Grid<Level>,
+    start: Coordinate,
+}
+impl Map {
+    /// Fewest steps from `start` to the best signal, if it can be reached at all.
+    pub fn find_shortest_path(&self, start: Coordinate) -> Option<u64> {
+        let mut visited: HashSet<Coordinate> = HashSet::from([start]);
+        let mut queue: VecDeque<Position> = VecDeque::from([Position {
+            coordinate: start,
+            steps: 0,
+        }]);
+        while let Some(pos) = queue.pop_front() {
+            let cell = &self.map[pos.coordinate];
+            if let Kind::End = cell.kind {
+                return Some(pos.steps);
+            }
+            for next in self.climbable(pos.coordinate) {
+                if visited.insert(next) {
+                    queue.push_back(Position {
+                        coordinate: next,
+                        steps: pos.steps + 1,
+                    });
+                }
+            }
+        }
+        None
+    }
+    /// Neighbours of `from` which are at most one level higher.
+    fn climbable(&self, from: Coordinate) -> impl Iterator<Item = Coordinate> + '_ {
+        let level = self.map[from].level;
+        self.map
+            .neighbours(from)
+            .filter(move |next| self.map[*next].level <= level + 1)
+    }
+    pub fn find_most_scenic_path(&self) -> Option<u64> {
+        self.map
+            .indexed_iter()
+            .filter(|(_, cell)| cell.level == 0)
+            .filter_map(|(cord, _)| self.find_shortest_path(cord))
+            .min()
+    }
+}
+struct Position {
+    coordinate: Coordinate,
+    steps: u64,
+}
+
+#[derive(Debug, Default)]
+enum Kind {
+    Start,
+    End,
+    #[default]
+    Default,
+}
+#[derive(Debug, Default)]
+struct Level {
+    level: u8,
+    kind: Kind,
+}
+
+impl TryFrom<char> for Level {
+    type Error = String;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'a'..='z' => Ok(Level {
+                level: c as u8 - b'a',
+                kind: Kind::Default,
+            }),
+            'S' => Ok(Level {
+                level: 0,
+                kind: Kind::Start,
+            }),
+            'E' => Ok(Level {
+                level: b'z' - b'a',
+                kind: Kind::End,
+            }),
+            _ => Err(format!("Invalid character: {}", c)),
+        }
+    }
+}
+
+impl Solver for Map {
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> color_eyre::Result<Self> {
+        let map = Grid::parse(input, |c| Level::try_from(c).map_err(|e| eyre!(e)))?;
+        let starts: Vec<Coordinate> = map
+            .indexed_iter()
+            .filter(|(_, level)| matches!(level.kind, Kind::Start))
+            .map(|(coordinate, _)| coordinate)
+            .collect();
+        let [start] = starts[..] else {
+            return Err(eyre!(
+                "the heightmap needs exactly one start, but has {}",
+                starts.len()
+            ));
+        };
+        Ok(Map { map, start })
+    }
+
+    fn part1(&self) -> color_eyre::Result<u64> {
+        self.find_shortest_path(self.start)
+            .ok_or_eyre("the best signal cannot be reached from the start")
+    }
+
+    fn part2(&self) -> color_eyre::Result<u64> {
+        self.find_most_scenic_path()
+            .ok_or_eyre("the best signal cannot be reached from any square with elevation a")
+    }
+}
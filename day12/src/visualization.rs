@@ -0,0 +1,100 @@
+use std::collections::HashSet;
+
+use common::Solver;
+use visualize::{Color, Frame, Visualize};
+use wasm_bindgen::{prelude::wasm_bindgen, JsValue};
+
+use crate::{Coordinate, Kind, Map};
+
+const VISITED: Color = [0.2, 0.4, 0.9, 1.0];
+const FRONTIER: Color = [1.0, 0.887, 0.250, 1.0];
+const END: Color = [1.0, 0.0, 0.0, 1.0];
+
+/// The breadth first search from the start, one step per distance from the start.
+pub struct Exploration {
+    map: Map,
+    visited: HashSet<Coordinate>,
+    frontier: Vec<Coordinate>,
+    steps: u64,
+}
+
+impl Exploration {
+    pub fn new(map: Map) -> Self {
+        let start = map.start;
+        Exploration {
+            map,
+            visited: HashSet::from([start]),
+            frontier: vec![start],
+            steps: 0,
+        }
+    }
+    /// Steps taken by the squares of the frontier.
+    pub fn steps(&self) -> u64 {
+        self.steps
+    }
+    /// Whether the best signal is part of the frontier.
+    pub fn found_end(&self) -> bool {
+        self.frontier
+            .iter()
+            .any(|c| matches!(self.map.map[*c].kind, Kind::End))
+    }
+}
+
+impl Visualize for Exploration {
+    fn size(&self) -> (usize, usize) {
+        (self.map.map.width(), self.map.map.height())
+    }
+
+    fn step(&mut self) -> bool {
+        if self.found_end() || self.frontier.is_empty() {
+            return false;
+        }
+        let mut frontier = vec![];
+        for coordinate in &self.frontier {
+            for next in self.map.climbable(*coordinate) {
+                if self.visited.insert(next) {
+                    frontier.push(next);
+                }
+            }
+        }
+        self.frontier = frontier;
+        self.steps += 1;
+        !self.found_end() && !self.frontier.is_empty()
+    }
+
+    fn draw(&self, frame: &mut Frame) {
+        for (coordinate, cell) in self.map.map.indexed_iter() {
+            let shade = 0.1 + cell.level as f32 / 50.0;
+            let color = match cell.kind {
+                Kind::End => END,
+                _ if self.visited.contains(&coordinate) => VISITED,
+                _ => [shade, shade, shade, 1.0],
+            };
+            frame.paint(coordinate, color);
+        }
+        for coordinate in &self.frontier {
+            frame.paint(*coordinate, FRONTIER);
+        }
+    }
+}
+
+/// Animates the search for the shortest path from the start to the best signal.
+#[wasm_bindgen]
+pub fn start(input: &str) -> Result<(), JsValue> {
+    let map = Map::parse(input).map_err(visualize::js_error)?;
+    visualize::animate("canvas", Exploration::new(map))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_exploration() {
+        let input = "Sabqponm\nabcryxxl\naccszExk\nacctuvwj\nabdefghi\n";
+        let mut exploration = Exploration::new(Map::parse(input).unwrap());
+        while exploration.step() {}
+        assert!(exploration.found_end());
+        assert_eq!(exploration.steps(), 31);
+    }
+}
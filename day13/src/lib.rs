@@ -0,0 +1,175 @@
+use core::fmt;
+use std::cmp::Ordering;
+
+use color_eyre::eyre::{eyre, WrapErr};
+use common::parse::{self, IResult};
+use common::Solver;
+use nom::branch::alt;
+use nom::character::complete::{char, multispace0, u32};
+
+use nom::combinator::{cut, map};
+use nom::error::context;
+use nom::multi::separated_list0;
+use nom::sequence::{delimited, preceded, terminated};
+
+#[derive(PartialEq, Eq, Clone)]
+pub enum Unit {
+    Number(u32),
+    List(Vec<Unit>),
+}
+
+impl Unit {
+    fn parse(input: &str) -> IResult<&str, Self> {
+        context(
+            "a number or a list",
+            alt((map(u32, Unit::Number), map(parse_unit_list, Unit::List))),
+        )(input)
+    }
+    fn with_slice<T>(&self, f: impl FnOnce(&[Unit]) -> T) -> T {
+        match self {
+            Unit::Number(n) => f(&[Unit::Number(*n)]),
+            Unit::List(l) => f(&l[..]),
+        }
+    }
+}
+
+impl fmt::Debug for Unit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Unit::Number(n) => write!(f, "{}", n),
+            Unit::List(l) => f.debug_list().entries(l).finish(),
+        }
+    }
+}
+
+pub struct DistressSignal {
+    packets: Vec<Unit>,
+}
+
+impl DistressSignal {
+    pub fn packets(&self) -> &[Unit] {
+        &self.packets
+    }
+}
+
+impl Solver for DistressSignal {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> color_eyre::Result<Self> {
+        let packets = parse::finish(input, parse_distress_signal)
+            .wrap_err("failed to parse the distress signal")?;
+        Ok(DistressSignal { packets })
+    }
+
+    fn part1(&self) -> color_eyre::Result<usize> {
+        if !self.packets.len().is_multiple_of(2) {
+            return Err(eyre!("the last packet has no partner"));
+        }
+        Ok(self
+            .packets
+            .chunks(2)
+            .enumerate()
+            .filter(|(_, pair)| pair[0] < pair[1])
+            .map(|(i, _)| i + 1)
+            .sum())
+    }
+
+    fn part2(&self) -> color_eyre::Result<usize> {
+        let deviders = vec![
+            Unit::List(vec![Unit::List(vec![Unit::Number(2)])]),
+            Unit::List(vec![Unit::List(vec![Unit::Number(6)])]),
+        ];
+
+        let mut signal = self.packets.clone();
+        signal.extend_from_slice(&deviders);
+        signal.sort();
+        Ok(signal
+            .iter()
+            .enumerate()
+            .filter_map(|(i, u)| deviders.iter().find(|d| *d == u).map(|_| i + 1))
+            .product())
+    }
+}
+
+fn parse_unit_list(input: &str) -> IResult<&str, Vec<Unit>> {
+    preceded(
+        char('['),
+        cut(terminated(
+            separated_list0(char(','), Unit::parse),
+            char(']'),
+        )),
+    )(input)
+}
+
+fn parse_distress_signal(input: &str) -> IResult<&str, Vec<Unit>> {
+    parse::many_to_end(delimited(
+        multispace0,
+        context(
+            "a packet like `[1,[2,3]]`",
+            map(parse_unit_list, Unit::List),
+        ),
+        multispace0,
+    ))(input)
+}
+
+impl PartialOrd for Unit {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Unit {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        match (self, other) {
+            (Unit::Number(l), Unit::Number(r)) => l.cmp(r),
+            (l, r) => l.with_slice(|l| {
+                r.with_slice(|r| {
+                    l.iter()
+                        .zip(r.iter())
+                        .map(|(l, r)| l.cmp(r))
+                        .find(|&ord| ord != Ordering::Equal)
+                        .unwrap_or_else(|| l.len().cmp(&r.len()))
+                })
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn test_parse_number_list() {
+        assert_eq!(
+            parse_unit_list("[1,2,3,4,5]"),
+            Ok((
+                "",
+                vec![
+                    Unit::Number(1),
+                    Unit::Number(2),
+                    Unit::Number(3),
+                    Unit::Number(4),
+                    Unit::Number(5)
+                ]
+            ))
+        );
+    }
+    #[test]
+    fn test_parse_embedded_list() {
+        assert_eq!(
+            parse_unit_list("[[1,2],3,4,5]"),
+            Ok((
+                "",
+                vec![
+                    Unit::List(vec![Unit::Number(1), Unit::Number(2),]),
+                    Unit::Number(3),
+                    Unit::Number(4),
+                    Unit::Number(5)
+                ]
+            ))
+        );
+    }
+}
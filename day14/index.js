@@ -1,4 +0,0 @@
-// For more comments about what's going on here, check out the `hello_world`
-// example.
-import('./pkg')
-  .catch(console.error);
\ No newline at end of file
@@ -4,23 +4,49 @@ use std::{
     fmt::{Debug, Formatter},
 };
 
-use grid::Grid;
+use color_eyre::eyre::WrapErr;
+use common::{
+    parse::{self, IResult},
+    Solver,
+};
+use geometry::{Grid, Point};
 use nom::{
+    branch::alt,
     bytes::complete::tag,
     character::complete::{line_ending, u32},
-    combinator::map,
-    multi::{many1, separated_list1},
+    combinator::{cut, eof, map},
+    error::context,
+    multi::separated_list1,
     sequence::{separated_pair, terminated},
-    IResult,
 };
-#[derive(Debug, Clone)]
-pub struct Coordinate(pub u32, pub u32);
+pub type Coordinate = Point<usize>;
 
-impl Coordinate {
-    fn parse(input: &str) -> IResult<&str, Coordinate> {
+fn parse_coordinate(input: &str) -> IResult<&str, Coordinate> {
+    context(
+        "`<x>,<y>`",
         map(separated_pair(u32, tag(","), u32), |(x, y)| {
-            Coordinate(x, y)
-        })(input)
+            Point::new(x as usize, y as usize)
+        }),
+    )(input)
+}
+
+/// What the sand finds below the lowest scanned rock.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Bottom {
+    /// Sand falls forever once it passes the lowest rock.
+    Abyss,
+    /// An infinite floor two rows below the lowest rock.
+    Floor,
+}
+
+/// Rock paths as read from the scan.
+pub struct Scan {
+    paths: Vec<Vec<Coordinate>>,
+}
+
+impl Scan {
+    pub fn paths(&self) -> &[Vec<Coordinate>] {
+        &self.paths
     }
 }
 
@@ -28,7 +54,7 @@ impl Coordinate {
 pub struct Cave {
     map: Grid<Cell>,
     done: bool,
-    pub min_x: u32,
+    pub min_x: usize,
     pub sand: VecDeque<Coordinate>,
 }
 
@@ -41,47 +67,47 @@ pub enum Cell {
 }
 
 impl Cave {
-    const SAND_SOURCE: Coordinate = Coordinate(500, 0);
-
-    #[allow(clippy::new_without_default)]
-    pub fn new() -> Self {
-        console_error_panic_hook::set_once();
-        let input = include_str!("input.txt");
-        let (_, scan) = parse_scan(&input).unwrap();
-        let max = find_max(&scan);
+    const SAND_SOURCE: Coordinate = Point { x: 500, y: 0 };
+
+    pub fn new(scan: &[Vec<Coordinate>], bottom: Bottom) -> Self {
+        let max = find_max(scan);
+        // Sand piling up on the floor can spread one column further than it is deep.
+        let width = std::cmp::max(max.x + 1, Cave::SAND_SOURCE.x + max.y + 3);
         let mut cave = Cave {
-            map: Grid::new(
-                (max.1 + 1) as usize,
-                (Cave::SAND_SOURCE.0 + max.1 + 1) as usize,
-            ),
+            map: Grid::new(width, max.y + 1),
             done: false,
             min_x: 0,
             sand: VecDeque::new(),
         };
         cave.add_rocks(scan);
-        cave.add_rocky_bottom();
+        if bottom == Bottom::Floor {
+            cave.add_rocky_bottom();
+        }
         cave.calculate_min_x();
         cave
     }
 
-    pub fn set_rock(&mut self, x: u32, y: u32) {
-        self.map[y as usize][x as usize] = Cell::Rock;
+    pub fn set_rock(&mut self, x: usize, y: usize) {
+        self.map[Point::new(x, y)] = Cell::Rock;
     }
     pub fn is_done(&self) -> bool {
         self.done
     }
+    pub fn sand_at_rest(&self) -> usize {
+        self.map.iter().filter(|c| **c == Cell::Sand).count()
+    }
 
-    fn add_rocks(&mut self, scan: Vec<Vec<Coordinate>>) {
+    fn add_rocks(&mut self, scan: &[Vec<Coordinate>]) {
         scan.iter().for_each(|l| {
             l.iter().enumerate().for_each(|(i, c)| {
                 if i == l.len() - 1 {
                     return;
                 }
-                let min = std::cmp::min(c.0, l[i + 1].0);
-                let max = std::cmp::max(c.0, l[i + 1].0);
+                let min = std::cmp::min(c.x, l[i + 1].x);
+                let max = std::cmp::max(c.x, l[i + 1].x);
                 (min..=max).for_each(|x| {
-                    let min = std::cmp::min(c.1, l[i + 1].1);
-                    let max = std::cmp::max(c.1, l[i + 1].1);
+                    let min = std::cmp::min(c.y, l[i + 1].y);
+                    let max = std::cmp::max(c.y, l[i + 1].y);
                     (min..=max).for_each(|y| self.set_rock(x, y))
                 });
             })
@@ -89,22 +115,19 @@ impl Cave {
     }
 
     pub fn step(&mut self) {
-        let fall_directions: [(i32, i32); 3] = [(0, 1), (-1, 1), (1, 1)];
+        let fall_directions: [(i8, i8); 3] = [(0, 1), (-1, 1), (1, 1)];
         self.sand.push_back(Cave::SAND_SOURCE);
         for c in self.sand.iter_mut() {
             let mut falling = true;
             for (dx, dy) in fall_directions.iter() {
-                let (nx, ny) = (c.0 as i64 + *dx as i64, c.1 as i64 + *dy as i64);
-                if nx as usize >= self.map.cols() {
-                    continue;
+                let Some(next) = c.offset(*dx, *dy).filter(|p| self.map.contains(*p)) else {
+                    // Nothing outside of the scanned area stops the sand from falling.
+                    self.done = true;
+                    return;
                 };
-                if nx < 0 {
-                    continue;
-                }
-                match self.map[ny as usize][nx as usize] {
+                match self.map[next] {
                     Cell::Air => {
-                        c.0 = nx as u32;
-                        c.1 = ny as u32;
+                        *c = next;
                         falling = true;
                         break;
                     }
@@ -112,15 +135,15 @@ impl Cave {
                 }
             }
             if !falling {
-                self.map[c.1 as usize][c.0 as usize] = Cell::Sand;
+                self.map[*c] = Cell::Sand;
             }
         }
-        if let Some(c) = self.sand.get(0) {
-            if self.map[c.1 as usize][c.0 as usize] == Cell::Sand {
+        if let Some(c) = self.sand.front() {
+            if self.map[*c] == Cell::Sand {
                 self.sand.pop_front();
             }
         }
-        if self.map[Cave::SAND_SOURCE.1 as usize][Cave::SAND_SOURCE.0 as usize] == Cell::Sand {
+        if self.map[Cave::SAND_SOURCE] == Cell::Sand {
             self.done = true;
         }
     }
@@ -131,36 +154,55 @@ impl Cave {
     }
     fn add_row(&mut self, cell: Cell) {
         self.map
-            .insert_row(self.map.rows(), vec![cell; self.map.cols()]);
-    }
-    fn add_column(&mut self) {
-        self.map
-            .insert_col(self.map.cols(), vec![Cell::Air; self.map.rows()]);
-        let x = self.map.rows() - 1;
-        let y = self.map.cols() - 1;
-        self.map[x][y] = Cell::Rock;
+            .push_row(vec![cell; self.map.width()])
+            .expect("the row spans the whole cave");
     }
     fn calculate_min_x(&mut self) {
-        self.min_x = Cave::SAND_SOURCE.0 as u32 - self.map.rows() as u32;
+        self.min_x = Cave::SAND_SOURCE.x.saturating_sub(self.map.height());
     }
     pub fn get_printable_cells(&self) -> Vec<Vec<Cell>> {
         self.map
-            .iter_rows()
-            .map(|line| {
-                line.skip(self.min_x as usize)
-                    .map(|c| c.clone())
-                    .collect::<Vec<Cell>>()
-            })
+            .rows()
+            .map(|line| line[self.min_x..].to_vec())
             .collect()
     }
 }
+impl Solver for Scan {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> color_eyre::Result<Self> {
+        let paths = parse::finish(input, parse_scan).wrap_err("failed to parse the scan")?;
+        Ok(Scan { paths })
+    }
+
+    fn part1(&self) -> color_eyre::Result<usize> {
+        Ok(self.simulate(Bottom::Abyss))
+    }
+
+    fn part2(&self) -> color_eyre::Result<usize> {
+        Ok(self.simulate(Bottom::Floor))
+    }
+}
+
+impl Scan {
+    fn simulate(&self, bottom: Bottom) -> usize {
+        let mut cave = Cave::new(&self.paths, bottom);
+        while !cave.is_done() {
+            cave.step()
+        }
+        cave.sand_at_rest()
+    }
+}
+
 impl Debug for Cave {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        for line in self.map.iter_rows() {
+        for line in self.map.rows() {
             writeln!(
                 f,
                 "{}",
-                line.skip(self.min_x as usize)
+                line[self.min_x..]
+                    .iter()
                     .map(|c| match c {
                         Cell::Rock => '#',
                         Cell::Air => '.',
@@ -174,23 +216,19 @@ impl Debug for Cave {
 }
 
 fn parse_coordinates(input: &str) -> IResult<&str, Vec<Coordinate>> {
-    terminated(separated_list1(tag(" -> "), Coordinate::parse), line_ending)(input)
+    terminated(
+        separated_list1(tag(" -> "), cut(parse_coordinate)),
+        context("` -> ` or end of line", cut(alt((line_ending, eof)))),
+    )(input)
 }
 pub fn parse_scan(input: &str) -> IResult<&str, Vec<Vec<Coordinate>>> {
-    many1(parse_coordinates)(input)
+    parse::many_to_end(parse_coordinates)(input)
 }
-pub fn find_max(scan: &Vec<Vec<Coordinate>>) -> Coordinate {
-    let mut max_x = 0;
-    let mut max_y = 0;
-    for line in scan {
-        for coord in line {
-            if coord.0 > max_x {
-                max_x = coord.0;
-            }
-            if coord.1 > max_y {
-                max_y = coord.1;
-            }
-        }
+pub fn find_max(scan: &[Vec<Coordinate>]) -> Coordinate {
+    let mut max = Point::new(0, 0);
+    for coord in scan.iter().flatten() {
+        max.x = std::cmp::max(max.x, coord.x);
+        max.y = std::cmp::max(max.y, coord.y);
     }
-    Coordinate(max_x, max_y)
+    max
 }
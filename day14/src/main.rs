@@ -1,8 +1,10 @@
-mod cave;
+use common::Solver;
+use day14::Scan;
 
-fn main() {
-    let mut cave = cave::Cave::new();
-    while !cave.is_done() {
-        cave.step()
-    }
+fn main() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+    let input = common::input::from_args(14)?;
+    let scan = Scan::parse(&input)?;
+    println!("Part 2: {}", scan.part2()?);
+    Ok(())
 }
@@ -0,0 +1,46 @@
+use common::Solver;
+use geometry::Point;
+use visualize::{Color, Frame, Visualize};
+use wasm_bindgen::{prelude::wasm_bindgen, JsValue};
+
+use crate::{Bottom, Cave, Cell, Scan};
+
+const ROCK: Color = [0.290, 0.188, 0.0551, 1.0];
+const SAND: Color = [1.00, 0.887, 0.250, 1.0];
+const FALLING_SAND: Color = [1.0, 0.0, 0.0, 1.0];
+
+impl Visualize for Cave {
+    fn size(&self) -> (usize, usize) {
+        let cells = self.get_printable_cells();
+        (cells[0].len(), cells.len())
+    }
+
+    fn step(&mut self) -> bool {
+        Cave::step(self);
+        !self.is_done()
+    }
+
+    fn draw(&self, frame: &mut Frame) {
+        for (y, row) in self.get_printable_cells().iter().enumerate() {
+            for (x, cell) in row.iter().enumerate() {
+                match cell {
+                    Cell::Rock => frame.paint(Point::new(x, y), ROCK),
+                    Cell::Sand => frame.paint(Point::new(x, y), SAND),
+                    Cell::Air => {}
+                }
+            }
+        }
+        for sand in &self.sand {
+            if let Some(x) = sand.x.checked_sub(self.min_x) {
+                frame.paint(Point::new(x, sand.y), FALLING_SAND);
+            }
+        }
+    }
+}
+
+/// Animates the sand filling the cave down to its floor.
+#[wasm_bindgen]
+pub fn start(input: &str) -> Result<(), JsValue> {
+    let scan = Scan::parse(input).map_err(visualize::js_error)?;
+    visualize::animate("canvas", Cave::new(scan.paths(), Bottom::Floor))
+}
@@ -0,0 +1,152 @@
+use color_eyre::eyre::{OptionExt, WrapErr};
+use common::{
+    parse::{self, IResult},
+    Solver,
+};
+use geometry::Point;
+use itertools::Itertools;
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{i64, line_ending},
+    combinator::{cut, eof, map},
+    error::context,
+    sequence::{delimited, preceded, separated_pair, terminated},
+};
+use std::{collections::HashSet, ops::RangeInclusive};
+
+const INSPECTED_ROW: i64 = 2_000_000;
+const SEARCH_LIMIT: i64 = 4_000_000;
+const TUNING_MULTIPLIER: i64 = 4_000_000;
+
+pub struct Map {
+    sensors: Vec<Sensor>,
+}
+
+impl Map {
+    pub fn find_ranges(&self, y: i64) -> impl Iterator<Item = RangeInclusive<i64>> {
+        let mut ranges = self.sensors.iter().fold(vec![], |mut a, sensor| {
+            let x = sensor.coordinate.x;
+            let distance = sensor.beacon_distance() - sensor.distance(Point::new(x, y));
+            if distance >= 0 {
+                let start = x - distance;
+                let end = x + distance;
+                a.push(start..=end);
+            }
+            a
+        });
+        ranges.sort_by(|a, b| a.start().cmp(b.start()));
+        ranges.into_iter().coalesce(|a, b| {
+            if a.end() + 1 >= *b.start() {
+                if a.end() < b.end() {
+                    Ok(*a.start()..=*b.end())
+                } else {
+                    Ok(a)
+                }
+            } else {
+                Err((a, b))
+            }
+        })
+    }
+    pub fn find_impossible_beacon_positions(&self, y: i64) -> i64 {
+        let beacons: HashSet<i64> = self
+            .sensors
+            .iter()
+            .filter(|s| s.beacon.y == y)
+            .map(|s| s.beacon.x)
+            .collect();
+
+        self.find_ranges(y)
+            .map(|r| {
+                let size = r.end() - r.start() + 1;
+                let becaons_in_range = beacons.iter().filter(|b| r.contains(b)).count();
+                size - becaons_in_range as i64
+            })
+            .sum::<i64>()
+    }
+    pub fn find_space_in_row(&self, y: i64, range: RangeInclusive<i64>) -> Option<Coordinate> {
+        let ranges: Vec<RangeInclusive<i64>> = self.find_ranges(y).collect();
+        if ranges.len() > 1 {
+            for r in ranges.iter() {
+                if range.contains(&(r.end() + 1)) {
+                    return Some(Point::new(r.end() + 1, y));
+                }
+            }
+        }
+        None
+    }
+    /// The only position with both coordinates in `0..=max` no sensor can see.
+    pub fn find_distress_beacon(&self, max: i64) -> Option<Coordinate> {
+        (0..=max).find_map(|y| self.find_space_in_row(y, 0..=max))
+    }
+}
+
+type Coordinate = Point<i64>;
+#[derive(Debug)]
+pub struct Sensor {
+    coordinate: Coordinate,
+    beacon: Coordinate,
+}
+impl Sensor {
+    fn new(coordinate: Coordinate, beacon: Coordinate) -> Self {
+        Self { coordinate, beacon }
+    }
+    fn parse(input: &str) -> IResult<&str, Sensor> {
+        let (input, (s, b)) = context(
+            "`Sensor at x=<n>, y=<n>: closest beacon is at x=<n>, y=<n>`",
+            separated_pair(
+                delimited(tag("Sensor at "), cut(parse_coordinates), tag(": ")),
+                cut(context(
+                    "`closest beacon is at x=<n>, y=<n>`",
+                    tag("closest beacon is at "),
+                )),
+                cut(parse_coordinates),
+            ),
+        )(input)?;
+        Ok((input, Self::new(s, b)))
+    }
+    fn distance(&self, other: Coordinate) -> i64 {
+        self.coordinate.manhattan(other)
+    }
+    fn beacon_distance(&self) -> i64 {
+        self.distance(self.beacon)
+    }
+}
+
+impl Solver for Map {
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> color_eyre::Result<Self> {
+        let sensors =
+            parse::finish(input, parse_sensors).wrap_err("failed to parse the sensors")?;
+        Ok(Map { sensors })
+    }
+
+    fn part1(&self) -> color_eyre::Result<i64> {
+        Ok(self.find_impossible_beacon_positions(INSPECTED_ROW))
+    }
+
+    fn part2(&self) -> color_eyre::Result<i64> {
+        self.find_distress_beacon(SEARCH_LIMIT)
+            .map(|beacon| beacon.x * TUNING_MULTIPLIER + beacon.y)
+            .ok_or_eyre("no space for the distress beacon found")
+    }
+}
+
+fn parse_coordinates(input: &str) -> IResult<&str, Coordinate> {
+    context(
+        "`x=<n>, y=<n>`",
+        map(
+            separated_pair(preceded(tag("x="), i64), tag(", y="), i64),
+            |(x, y)| Point::new(x, y),
+        ),
+    )(input)
+}
+
+fn parse_sensors(input: &str) -> IResult<&str, Vec<Sensor>> {
+    parse::many_to_end(terminated(
+        Sensor::parse,
+        context("end of line", cut(alt((line_ending, eof)))),
+    ))(input)
+}
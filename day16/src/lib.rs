@@ -0,0 +1,244 @@
+use std::collections::{HashMap, HashSet};
+
+use color_eyre::eyre::{eyre, WrapErr};
+use common::{
+    parse::{self, IResult},
+    Solver,
+};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{alpha1, line_ending, u64},
+    combinator::{cut, eof, map},
+    error::context,
+    multi::separated_list1,
+    sequence::{preceded, terminated, tuple},
+};
+
+const START: &str = "AA";
+
+#[derive(Debug, Clone)]
+pub struct Room {
+    name: String,
+    pressure: u64,
+    tunnels: Vec<String>,
+    connections: HashMap<String, u64>,
+}
+impl Room {
+    fn parse(input: &str) -> IResult<&str, Room> {
+        let (input, _) = tag("Valve ")(input)?;
+        let (input, (name, valve, tunnels, _)) = context(
+            "`Valve <name> has flow rate=<n>; tunnels lead to valves <name>, ...`",
+            cut(tuple((
+                terminated(alpha1, tag(" has flow rate=")),
+                terminated(u64, tag(";")),
+                preceded(
+                    context(
+                        "` tunnels lead to valves ` or ` tunnel leads to valve `",
+                        alt((
+                            tag(" tunnels lead to valves "),
+                            tag(" tunnel leads to valve "),
+                        )),
+                    ),
+                    separated_list1(tag(", "), map(alpha1, |s: &str| s.to_string())),
+                ),
+                context("end of line", alt((line_ending, eof))),
+            ))),
+        )(input)?;
+        Ok((
+            input,
+            Room {
+                name: name.to_string(),
+                pressure: valve,
+                tunnels,
+                connections: HashMap::new(),
+            },
+        ))
+    }
+}
+#[derive(Clone, Debug)]
+pub struct State {
+    released_valves: HashSet<String>,
+    released_pressure: u64,
+    time_left: u64,
+    current: String,
+    elefant_current: String,
+    elefant_time_left: u64,
+}
+
+impl State {
+    pub fn new(current: String, elefant_current: String, minutes: u64) -> State {
+        State {
+            released_valves: HashSet::new(),
+            released_pressure: 0,
+            time_left: minutes,
+            elefant_time_left: minutes,
+            current,
+            elefant_current,
+        }
+    }
+    fn walk(&mut self, room: &Room) {
+        self.time_left -= room.connections.get(&self.current).unwrap() as &u64;
+        self.current = room.name.clone();
+    }
+    fn elefant_walk(&mut self, room: &Room) {
+        self.elefant_time_left -= room.connections.get(&self.elefant_current).unwrap() as &u64;
+        self.elefant_current = room.name.clone();
+    }
+    fn elefant_release(&mut self, pressure: u64, room: String) {
+        self.elefant_time_left -= 1;
+        self.released_valves.insert(room.clone());
+        self.released_pressure += pressure;
+    }
+    fn release(&mut self, pressure: u64, room: String) {
+        self.time_left -= 1;
+        self.released_valves.insert(room.clone());
+        self.released_pressure += pressure;
+    }
+}
+pub struct Cave {
+    rooms: HashMap<String, Room>,
+}
+impl Cave {
+    /// Most pressure released when only `state.current` walks through the cave.
+    pub fn find_max_pressure_alone(&self, state: State) -> u64 {
+        let room = self.rooms.get(&state.current).unwrap();
+        room.connections
+            .iter()
+            .filter(|(name, distance)| {
+                !state.released_valves.contains(*name)
+                    && self.rooms.get(*name).unwrap().pressure != 0
+                    && state.time_left > *distance + 1
+            })
+            .map(|(name, distance)| {
+                let next = self.rooms.get(name).unwrap();
+                let pressure = next.pressure * (state.time_left - distance - 1);
+                let mut new_state = state.clone();
+                new_state.walk(next);
+                new_state.release(pressure, name.clone());
+                self.find_max_pressure_alone(new_state)
+            })
+            .max()
+            .unwrap_or(state.released_pressure)
+    }
+    pub fn find_max_pressure(&self, state: State) -> u64 {
+        let room = self.rooms.get(&state.current).unwrap();
+        let connections: Vec<(String, u64)> = room
+            .connections
+            .clone()
+            .into_iter()
+            .filter(|(name, distance)| {
+                !state.released_valves.contains(name)
+                    && self.rooms.get(name).unwrap().pressure != 0
+                    && state.time_left > distance + 1
+            })
+            .collect();
+        let elefant_room = self.rooms.get(&state.elefant_current).unwrap();
+        let elefant_connections: Vec<(String, u64)> = elefant_room
+            .connections
+            .clone()
+            .into_iter()
+            .filter(|(name, distance)| {
+                !state.released_valves.contains(name)
+                    && self.rooms.get(name).unwrap().pressure != 0
+                    && state.elefant_time_left > distance + 1
+            })
+            .collect();
+        connections
+            .iter()
+            .map(|(name, distance)| {
+                elefant_connections
+                    .iter()
+                    .filter_map(|(elefant_name, elefant_distance)| {
+                        if name == elefant_name {
+                            return None;
+                        }
+
+                        let mut new_state = state.clone();
+
+                        if state.time_left > distance + 1 {
+                            let next = self.rooms.get(name).unwrap();
+                            let pressure = next.pressure * (state.time_left - distance - 1);
+                            new_state.walk(next);
+                            new_state.release(pressure, name.clone());
+                        }
+                        if state.elefant_time_left > elefant_distance + 1 {
+                            let next = self.rooms.get(elefant_name).unwrap();
+                            let pressure =
+                                next.pressure * (state.elefant_time_left - elefant_distance - 1);
+                            new_state.elefant_walk(next);
+                            new_state.elefant_release(pressure, elefant_name.clone());
+                        }
+                        Some(self.find_max_pressure(new_state))
+                    })
+                    .max()
+                    .unwrap_or(state.released_pressure)
+            })
+            .max()
+            .unwrap_or(state.released_pressure)
+    }
+}
+impl Solver for Cave {
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> color_eyre::Result<Self> {
+        let mut rooms: HashMap<String, Room> =
+            parse::finish(input, parse::many_to_end(Room::parse))
+                .wrap_err("failed to parse the valves")?
+                .into_iter()
+                .map(|room| (room.name.clone(), room))
+                .collect();
+        for room in rooms.values() {
+            if let Some(tunnel) = room.tunnels.iter().find(|t| !rooms.contains_key(*t)) {
+                return Err(eyre!("valve {} leads to unknown valve {tunnel}", room.name));
+            }
+        }
+        populate_network(&mut rooms);
+        Ok(Cave { rooms })
+    }
+
+    fn part1(&self) -> color_eyre::Result<u64> {
+        self.ensure_start()?;
+        let state = State::new(START.to_string(), START.to_string(), 30);
+        Ok(self.find_max_pressure_alone(state))
+    }
+
+    fn part2(&self) -> color_eyre::Result<u64> {
+        self.ensure_start()?;
+        let state = State::new(START.to_string(), START.to_string(), 26);
+        Ok(self.find_max_pressure(state))
+    }
+}
+impl Cave {
+    fn ensure_start(&self) -> color_eyre::Result<()> {
+        if !self.rooms.contains_key(START) {
+            return Err(eyre!("there is no valve {START} to start from"));
+        }
+        Ok(())
+    }
+}
+fn populate_network(rooms: &mut HashMap<String, Room>) {
+    let lookup = rooms.clone();
+    for (name, room) in rooms.iter_mut() {
+        let mut connections: HashMap<String, u64> = HashMap::new();
+        build_connections(&mut connections, name.clone(), &lookup, 0);
+        room.connections = connections;
+    }
+}
+
+fn build_connections(
+    connections: &mut HashMap<String, u64>,
+    current: String,
+    rooms: &HashMap<String, Room>,
+    mut distance: u64,
+) {
+    let tunnels = rooms.get(&current).unwrap().tunnels.clone();
+    distance += 1;
+    tunnels.iter().for_each(|tunnel| {
+        if !connections.contains_key(tunnel) || connections[tunnel] > distance {
+            connections.insert(tunnel.clone(), distance);
+            build_connections(connections, tunnel.clone(), rooms, distance);
+        }
+    })
+}
@@ -1,7 +1,6 @@
 use std::{collections::HashMap, iter::Cycle, iter::Enumerate, vec::IntoIter};
 
-#[derive(Clone, Debug, PartialEq)]
-pub struct Coordinate((u64, u64));
+use geometry::Point;
 
 #[derive(Clone)]
 struct Shape {
@@ -9,7 +8,7 @@ struct Shape {
     y: u64,
     width: u64,
     height: u64,
-    coordinates: Vec<Coordinate>,
+    coordinates: Vec<Point<u64>>,
     kind: ShapeKind,
 }
 #[derive(Clone, PartialEq)]
@@ -29,25 +28,25 @@ impl Shape {
             .iter()
             .any(|c| other_coordinates.contains(c))
     }
-    fn get_coordinates(&self) -> Vec<Coordinate> {
+    fn get_coordinates(&self) -> Vec<Point<u64>> {
         self.coordinates
             .iter()
-            .map(|Coordinate((x, y))| Coordinate((x + self.x, y + self.y)))
+            .map(|c| *c + Point::new(self.x, self.y))
             .collect()
     }
 }
-#[derive(Clone)]
-enum Direction {
+#[derive(Clone, Debug)]
+pub enum Direction {
     Left,
     Right,
 }
 
 impl Direction {
-    fn parse(c: char) -> Option<Direction> {
+    pub fn parse(c: char) -> Option<Direction> {
         match c {
             '<' => Some(Direction::Left),
             '>' => Some(Direction::Right),
-            _ => unreachable!("Invalid direction"),
+            _ => None,
         }
     }
 }
@@ -57,10 +56,7 @@ struct DirectionIterator {
     index: usize,
 }
 impl DirectionIterator {
-    fn new() -> DirectionIterator {
-        let input = include_str!("input.txt");
-        let directions: Vec<Direction> = input.chars().filter_map(Direction::parse).collect();
-
+    fn new(directions: Vec<Direction>) -> DirectionIterator {
         DirectionIterator {
             directions: directions.into_iter().enumerate().cycle(),
             index: 0,
@@ -85,16 +81,17 @@ pub struct Cave {
     direction_iterator: DirectionIterator,
     down: bool,
     loop_detection: HashMap<(usize, u64), usize>,
+    rocks: usize,
     done: bool,
+    result: Option<u64>,
 }
 
 impl Cave {
     const NEW_SHAPE_POSITION_X: u64 = 2;
     const EMPTY_ROWS: u64 = 3;
     const WIDTH: u64 = 7;
-    //const AMOUNT: usize = 2022;
-    const AMOUNT: usize = 1000000000000;
-    pub fn new() -> Cave {
+    /// A cave in which `rocks` rocks will fall, pushed around by the jets in `directions`.
+    pub fn new(directions: Vec<Direction>, rocks: usize) -> Cave {
         let shape_iterator = create_shapes();
 
         let mut cave = Cave {
@@ -103,17 +100,22 @@ impl Cave {
             falling_shape: None,
             stationary_shapes: vec![],
             shape_iterator: shape_iterator.into_iter().cycle(),
-            direction_iterator: DirectionIterator::new(),
+            direction_iterator: DirectionIterator::new(directions),
             down: false,
             loop_detection: HashMap::new(),
+            rocks,
             done: false,
+            result: None,
         };
         cave.add_falling_shape();
-        return cave;
+        cave
     }
     pub fn is_done(&self) -> bool {
         self.done
     }
+    pub fn result(&self) -> Option<u64> {
+        self.result
+    }
     pub fn step(&mut self) {
         match self.down {
             true => self.move_down(),
@@ -147,6 +149,11 @@ impl Cave {
     fn add_satationary_shape(&mut self, shape: Shape) {
         self.falling_shape = None;
         self.stationary_shapes.push(shape);
+        if self.stationary_shapes.len() == self.rocks {
+            self.result = Some(self.get_highest_point());
+            self.done = true;
+            return;
+        }
         self.find_repetition();
         self.loop_detection.insert(
             (
@@ -167,6 +174,20 @@ impl Cave {
         }
         self.falling_shape = Some(Shape { x, y, ..shape });
     }
+    /// Every cell taken by a rock, together with whether the rock is still falling.
+    pub fn rock_cells(&self) -> impl Iterator<Item = (Point<u64>, bool)> + '_ {
+        let resting = self
+            .stationary_shapes
+            .iter()
+            .flat_map(|shape| shape.get_coordinates())
+            .map(|c| (c, false));
+        let falling = self
+            .falling_shape
+            .iter()
+            .flat_map(|shape| shape.get_coordinates())
+            .map(|c| (c, true));
+        resting.chain(falling)
+    }
     pub fn get_highest_point(&self) -> u64 {
         self.stationary_shapes
             .iter()
@@ -198,20 +219,14 @@ impl Cave {
             self.stationary_shapes.last().unwrap().x,
         )) {
             if self.stationary_shapes[*index].kind == self.stationary_shapes.last().unwrap().kind {
-                for i in 0..self.stationary_shapes.len() as usize - index {
-                    let a = &self.stationary_shapes[(index - i) as usize];
-                    let b = &self.stationary_shapes
-                        [(self.stationary_shapes.len() - 1 - i as usize) as usize];
+                for i in 0..self.stationary_shapes.len() - index {
+                    let a = &self.stationary_shapes[index - i];
+                    let b = &self.stationary_shapes[self.stationary_shapes.len() - 1 - i];
                     assert!(a.kind == b.kind);
                     if a.x != b.x {
                         return false;
                     }
                 }
-                println!(
-                    "Found repetition at {} and {}",
-                    self.stationary_shapes.len(),
-                    index
-                );
                 let highest_point = self.get_highest_point();
                 let repetition_length = self.stationary_shapes.len() - 1 - index;
                 let highest_point_index = self.stationary_shapes[0..=*index]
@@ -220,17 +235,16 @@ impl Cave {
                     .max()
                     .unwrap();
                 let max_repetition = highest_point - highest_point_index;
-                let missing = Self::AMOUNT - (self.stationary_shapes.len());
+                let missing = self.rocks - (self.stationary_shapes.len());
                 let mut result = highest_point;
                 result += missing as u64 / repetition_length as u64 * max_repetition;
-                result += self.stationary_shapes
-                    [*index..=*index + (missing as usize % repetition_length)]
+                result += self.stationary_shapes[*index..=*index + (missing % repetition_length)]
                     .iter()
                     .map(|s| s.y + s.height)
                     .max()
                     .unwrap()
                     - highest_point_index;
-                println!("Heighest point: {}", result);
+                self.result = Some(result);
                 self.done = true;
                 return true;
             }
@@ -246,10 +260,10 @@ fn create_shapes() -> Vec<Shape> {
             width: 4,
             height: 1,
             coordinates: vec![
-                Coordinate((0, 0)),
-                Coordinate((1, 0)),
-                Coordinate((2, 0)),
-                Coordinate((3, 0)),
+                Point::new(0, 0),
+                Point::new(1, 0),
+                Point::new(2, 0),
+                Point::new(3, 0),
             ],
             kind: ShapeKind::Horizontal,
         },
@@ -259,11 +273,11 @@ fn create_shapes() -> Vec<Shape> {
             width: 3,
             height: 3,
             coordinates: vec![
-                Coordinate((1, 0)),
-                Coordinate((0, 1)),
-                Coordinate((1, 1)),
-                Coordinate((1, 2)),
-                Coordinate((2, 1)),
+                Point::new(1, 0),
+                Point::new(0, 1),
+                Point::new(1, 1),
+                Point::new(1, 2),
+                Point::new(2, 1),
             ],
             kind: ShapeKind::PlusShape,
         },
@@ -273,11 +287,11 @@ fn create_shapes() -> Vec<Shape> {
             width: 3,
             height: 3,
             coordinates: vec![
-                Coordinate((2, 2)),
-                Coordinate((2, 1)),
-                Coordinate((0, 0)),
-                Coordinate((1, 0)),
-                Coordinate((2, 0)),
+                Point::new(2, 2),
+                Point::new(2, 1),
+                Point::new(0, 0),
+                Point::new(1, 0),
+                Point::new(2, 0),
             ],
             kind: ShapeKind::LShape,
         },
@@ -287,10 +301,10 @@ fn create_shapes() -> Vec<Shape> {
             width: 1,
             height: 4,
             coordinates: vec![
-                Coordinate((0, 0)),
-                Coordinate((0, 1)),
-                Coordinate((0, 2)),
-                Coordinate((0, 3)),
+                Point::new(0, 0),
+                Point::new(0, 1),
+                Point::new(0, 2),
+                Point::new(0, 3),
             ],
             kind: ShapeKind::Vertical,
         },
@@ -300,10 +314,10 @@ fn create_shapes() -> Vec<Shape> {
             width: 2,
             height: 2,
             coordinates: vec![
-                Coordinate((0, 0)),
-                Coordinate((1, 0)),
-                Coordinate((0, 1)),
-                Coordinate((1, 1)),
+                Point::new(0, 0),
+                Point::new(1, 0),
+                Point::new(0, 1),
+                Point::new(1, 1),
             ],
             kind: ShapeKind::Square,
         },
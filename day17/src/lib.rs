@@ -0,0 +1,59 @@
+use color_eyre::eyre::eyre;
+use common::Solver;
+
+mod cave;
+mod visualization;
+
+pub use cave::{Cave, Direction};
+pub use visualization::start;
+
+const SHORT_TOWER: usize = 2022;
+const TALL_TOWER: usize = 1_000_000_000_000;
+
+/// The pattern of hot gas jets pushing the falling rocks.
+pub struct Jets {
+    directions: Vec<Direction>,
+}
+
+impl Jets {
+    pub fn directions(&self) -> &[Direction] {
+        &self.directions
+    }
+}
+
+impl Solver for Jets {
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> color_eyre::Result<Self> {
+        let directions = input
+            .trim_end()
+            .chars()
+            .map(|c| Direction::parse(c).ok_or_else(|| eyre!("invalid jet direction {c:?}")))
+            .collect::<color_eyre::Result<_>>()?;
+        Ok(Jets { directions })
+    }
+
+    fn part1(&self) -> color_eyre::Result<u64> {
+        self.tower_height(SHORT_TOWER)
+    }
+
+    fn part2(&self) -> color_eyre::Result<u64> {
+        self.tower_height(TALL_TOWER)
+    }
+}
+
+impl Jets {
+    /// Height of the tower after `rocks` rocks came to rest.
+    pub fn tower_height(&self, rocks: usize) -> color_eyre::Result<u64> {
+        if self.directions.is_empty() {
+            return Err(eyre!("there are no jets"));
+        }
+        let mut cave = Cave::new(self.directions.clone(), rocks);
+        while !cave.is_done() {
+            cave.step();
+        }
+        cave.result()
+            .ok_or_else(|| eyre!("the tower did not repeat"))
+    }
+}
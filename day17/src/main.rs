@@ -1,8 +1,10 @@
-mod cave;
-fn main() {
-    let mut cave = cave::Cave::new();
-    while !cave.is_done() {
-        cave.step();
-    }
-    println!("Part 1: {}", cave.get_highest_point());
+use common::Solver;
+use day17::Jets;
+
+fn main() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+    let input = common::input::from_args(17)?;
+    let jets = Jets::parse(&input)?;
+    println!("Part 2: {}", jets.part2()?);
+    Ok(())
 }
@@ -0,0 +1,43 @@
+use common::Solver;
+use geometry::Point;
+use visualize::{Color, Frame, Visualize};
+use wasm_bindgen::{prelude::wasm_bindgen, JsValue};
+
+use crate::{Cave, Jets, SHORT_TOWER};
+
+/// Rows shown of the top of the tower.
+const VISIBLE_ROWS: u64 = 60;
+const RESTING: Color = [0.5, 0.5, 0.55, 1.0];
+const FALLING: Color = [1.0, 0.6, 0.1, 1.0];
+
+impl Visualize for Cave {
+    fn size(&self) -> (usize, usize) {
+        (self.width as usize, VISIBLE_ROWS as usize)
+    }
+
+    fn step(&mut self) -> bool {
+        Cave::step(self);
+        !self.is_done()
+    }
+
+    fn draw(&self, frame: &mut Frame) {
+        // Rows count upwards in the cave but downwards in the frame.
+        let top = self.height.max(VISIBLE_ROWS);
+        for (cell, falling) in self.rock_cells() {
+            if cell.y + VISIBLE_ROWS < top {
+                continue;
+            }
+            let color = if falling { FALLING } else { RESTING };
+            let y = top - 1 - cell.y;
+            frame.paint(Point::new(cell.x as usize, y as usize), color);
+        }
+    }
+}
+
+/// Animates the rocks falling until the tower repeats or 2022 rocks came to rest.
+#[wasm_bindgen]
+pub fn start(input: &str) -> Result<(), JsValue> {
+    let jets = Jets::parse(input).map_err(visualize::js_error)?;
+    let cave = Cave::new(jets.directions().to_vec(), SHORT_TOWER);
+    visualize::animate("canvas", cave)
+}
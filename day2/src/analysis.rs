@@ -0,0 +1,157 @@
+use std::fmt::{self, Display, Formatter};
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::{Game, Move, PlayMove, Round, Rules, StrategyGuide};
+
+/// How the player could have fared against the opponent's moves of a strategy guide.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Analysis {
+    /// Total score of always playing the same move, for every move
+    pub fixed: Vec<(Move, i32)>,
+    /// The highest scoring answer to every move of the opponent, with its score
+    pub best_responses: Vec<(Move, Move, i32)>,
+    /// Total score of always giving the best response
+    pub maximum: i32,
+    pub random_opponent: MonteCarlo,
+    names: Vec<String>,
+}
+
+/// Totals of the guide's moves against an opponent choosing uniformly random moves.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MonteCarlo {
+    pub trials: usize,
+    pub mean: f64,
+    pub standard_deviation: f64,
+}
+
+fn score(game: &Game, rules: &Rules, opponent: Move, player: Move) -> i32 {
+    Round::new(opponent, player).score(game, rules)
+}
+
+impl StrategyGuide {
+    /// Analyses the opponent's moves and simulates `trials` games with random opponents.
+    ///
+    /// The same `seed` gives the same estimate.
+    pub fn analyse(&self, trials: usize, seed: u64) -> color_eyre::Result<Analysis> {
+        let (game, rules) = (self.game(), self.rules());
+        let opponent_moves: Vec<Move> = self.entries().iter().map(|e| e.opponent_move).collect();
+        let fixed = game
+            .moves()
+            .map(|player| {
+                let total = opponent_moves
+                    .iter()
+                    .map(|opponent| score(game, rules, *opponent, player))
+                    .sum();
+                (player, total)
+            })
+            .collect();
+        let best_responses: Vec<(Move, Move, i32)> = game
+            .moves()
+            .map(|opponent| {
+                let (best, points) = game
+                    .moves()
+                    .map(|player| (player, score(game, rules, opponent, player)))
+                    .rev()
+                    .max_by_key(|(_, points)| *points)
+                    .expect("a game has at least one move");
+                (opponent, best, points)
+            })
+            .collect();
+        let maximum = opponent_moves
+            .iter()
+            .map(|opponent| best_responses[opponent.index()].2)
+            .sum();
+
+        let player_moves: Vec<Move> = self
+            .rounds(&PlayMove)?
+            .iter()
+            .map(|round| round.player_move)
+            .collect();
+        let mut rng = StdRng::seed_from_u64(seed);
+        let moves: Vec<Move> = game.moves().collect();
+        let totals: Vec<f64> = (0..trials)
+            .map(|_| {
+                let total: i32 = player_moves
+                    .iter()
+                    .map(|player| {
+                        let opponent = moves[rng.gen_range(0..moves.len())];
+                        score(game, rules, opponent, *player)
+                    })
+                    .sum();
+                total as f64
+            })
+            .collect();
+        let mean = totals.iter().sum::<f64>() / trials.max(1) as f64;
+        let variance =
+            totals.iter().map(|t| (t - mean).powi(2)).sum::<f64>() / trials.max(1) as f64;
+
+        Ok(Analysis {
+            fixed,
+            best_responses,
+            maximum,
+            random_opponent: MonteCarlo {
+                trials,
+                mean,
+                standard_deviation: variance.sqrt(),
+            },
+            names: game.moves().map(|m| game.name(m).to_string()).collect(),
+        })
+    }
+}
+
+impl Display for Analysis {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let name = |m: &Move| &self.names[m.index()];
+        writeln!(f, "always playing the same move:")?;
+        for (player, total) in &self.fixed {
+            writeln!(f, "  {:<10} {total:>8}", name(player))?;
+        }
+        writeln!(f, "best response:")?;
+        for (opponent, best, points) in &self.best_responses {
+            writeln!(
+                f,
+                "  {:<10} -> {:<10} {points:>3} points",
+                name(opponent),
+                name(best)
+            )?;
+        }
+        writeln!(f, "maximum total: {}", self.maximum)?;
+        let random = &self.random_opponent;
+        writeln!(
+            f,
+            "guide against a random opponent: {:.1} ± {:.1} over {} games",
+            random.mean, random.standard_deviation, random.trials
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use common::Solver;
+
+    use super::*;
+
+    #[test]
+    fn test_analyse() {
+        let guide = StrategyGuide::parse("A Y\nB X\nC Z\n").unwrap();
+        let analysis = guide.analyse(1000, 1).unwrap();
+        // Always rock: draw 4, loss 1, win 7.
+        assert_eq!(
+            analysis.fixed,
+            vec![(Move::ROCK, 12), (Move::PAPER, 15), (Move::SCISSORS, 18)]
+        );
+        assert_eq!(
+            analysis.best_responses,
+            vec![
+                (Move::ROCK, Move::PAPER, 8),
+                (Move::PAPER, Move::SCISSORS, 9),
+                (Move::SCISSORS, Move::ROCK, 7)
+            ]
+        );
+        assert_eq!(analysis.maximum, 24);
+        // Every move scores 1 + 3 on average against a random one, plus 1 for paper and 2 for scissors.
+        assert!((analysis.random_opponent.mean - 15.0).abs() < 0.5);
+        assert_eq!(guide.analyse(1000, 1).unwrap(), analysis);
+    }
+}
@@ -0,0 +1,254 @@
+use color_eyre::eyre::{eyre, OptionExt};
+use serde::Deserialize;
+
+use crate::RoundResult;
+
+/// A move of a game, identified by its position in the game's list of moves.
+#[derive(PartialEq, Eq, Hash, PartialOrd, Ord, Clone, Copy, Debug)]
+pub struct Move(usize);
+
+impl Move {
+    /// The moves of [`Game::classic`].
+    pub const ROCK: Move = Move(0);
+    pub const PAPER: Move = Move(1);
+    pub const SCISSORS: Move = Move(2);
+
+    pub fn index(&self) -> usize {
+        self.0
+    }
+}
+
+/// A move as written in a game definition, everything but the name may be left out.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct MoveDefinition {
+    pub name: String,
+    /// Letter of the move in the opponent's column
+    pub opponent: Option<char>,
+    /// Letter of the move in the player's column
+    pub player: Option<char>,
+    pub points: Option<i32>,
+    /// Names of the moves this one wins against
+    #[serde(default)]
+    pub beats: Vec<String>,
+}
+
+impl MoveDefinition {
+    /// A move which takes everything else from its position in the game.
+    pub fn named(name: &str) -> Self {
+        MoveDefinition {
+            name: name.to_string(),
+            opponent: None,
+            player: None,
+            points: None,
+            beats: vec![],
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct MoveInfo {
+    name: String,
+    opponent: char,
+    player: char,
+    points: i32,
+}
+
+/// A hand game in which one of any two different moves wins against the other.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    moves: Vec<MoveInfo>,
+    /// `beats[a][b]` tells whether `a` wins against `b`
+    beats: Vec<Vec<bool>>,
+}
+
+impl Game {
+    /// Rock, paper and scissors.
+    pub fn classic() -> Self {
+        Self::cyclic(&["rock", "paper", "scissors"]).expect("three moves make a cyclic game")
+    }
+    /// Rock, paper, scissors, lizard and Spock.
+    pub fn rock_paper_scissors_lizard_spock() -> Self {
+        Self::cyclic(&["rock", "spock", "paper", "lizard", "scissors"])
+            .expect("five moves make a cyclic game")
+    }
+    /// A game in which every move beats the half of the other moves listed right before it,
+    /// wrapping around at the start.
+    ///
+    /// Moves are worth their position starting at 1. The opponent writes them as `A`, `B`, ...
+    /// and the player with as many letters ending at `Z`, like `X`, `Y` and `Z` for three moves.
+    pub fn cyclic(names: &[&str]) -> color_eyre::Result<Self> {
+        Self::from_definition(
+            names
+                .iter()
+                .map(|name| MoveDefinition::named(name))
+                .collect(),
+        )
+    }
+    /// A game made of `moves`.
+    ///
+    /// Without any `beats`, the moves form a [cyclic](Game::cyclic) game. Otherwise the
+    /// listed wins have to decide every pair of different moves exactly once.
+    pub fn from_definition(moves: Vec<MoveDefinition>) -> color_eyre::Result<Self> {
+        let count = moves.len();
+        if !(1..=26).contains(&count) {
+            return Err(eyre!("a game needs 1 to 26 moves, but has {count}"));
+        }
+        let index = |name: &str| {
+            moves
+                .iter()
+                .position(|m| m.name == name)
+                .ok_or_else(|| eyre!("there is no move `{name}`"))
+        };
+        let mut beats = vec![vec![false; count]; count];
+        if moves.iter().all(|m| m.beats.is_empty()) {
+            if count.is_multiple_of(2) {
+                return Err(eyre!(
+                    "a cyclic game needs an odd number of moves, but has {count}"
+                ));
+            }
+            for (winner, row) in beats.iter_mut().enumerate() {
+                for distance in 1..=count / 2 {
+                    row[(winner + count - distance) % count] = true;
+                }
+            }
+        } else {
+            for (winner, definition) in moves.iter().enumerate() {
+                for loser in &definition.beats {
+                    beats[winner][index(loser)?] = true;
+                }
+            }
+        }
+        for a in 0..count {
+            if index(&moves[a].name)? != a {
+                return Err(eyre!("the move `{}` is defined twice", moves[a].name));
+            }
+            for b in 0..count {
+                let decided = beats[a][b] as u8 + beats[b][a] as u8;
+                if (a == b && decided != 0) || (a != b && decided != 1) {
+                    return Err(eyre!(
+                        "`{}` and `{}` need exactly one winner",
+                        moves[a].name,
+                        moves[b].name
+                    ));
+                }
+            }
+        }
+        let moves: Vec<MoveInfo> = moves
+            .into_iter()
+            .enumerate()
+            .map(|(i, m)| MoveInfo {
+                name: m.name,
+                opponent: m.opponent.unwrap_or((b'A' + i as u8) as char),
+                player: m.player.unwrap_or((b'Z' - (count - 1 - i) as u8) as char),
+                points: m.points.unwrap_or(i as i32 + 1),
+            })
+            .collect();
+        for (i, a) in moves.iter().enumerate() {
+            for b in &moves[i + 1..] {
+                if a.opponent == b.opponent || a.player == b.player {
+                    return Err(eyre!("`{}` and `{}` share a letter", a.name, b.name));
+                }
+            }
+        }
+        Ok(Game { moves, beats })
+    }
+
+    pub fn moves(&self) -> impl DoubleEndedIterator<Item = Move> {
+        (0..self.moves.len()).map(Move)
+    }
+    pub fn name(&self, m: Move) -> &str {
+        &self.moves[m.0].name
+    }
+    pub fn points(&self, m: Move) -> i32 {
+        self.moves[m.0].points
+    }
+    pub fn find(&self, name: &str) -> Option<Move> {
+        self.moves.iter().position(|m| m.name == name).map(Move)
+    }
+    pub fn beats(&self, a: Move, b: Move) -> bool {
+        self.beats[a.0][b.0]
+    }
+    /// How the round ends for the one playing `player` against `opponent`.
+    pub fn result(&self, player: Move, opponent: Move) -> RoundResult {
+        if player == opponent {
+            RoundResult::Draw
+        } else if self.beats(player, opponent) {
+            RoundResult::Win
+        } else {
+            RoundResult::Lose
+        }
+    }
+    /// The first move which gets `result` when played against `opponent`.
+    pub fn answer_for(&self, opponent: Move, result: RoundResult) -> Option<Move> {
+        self.moves().find(|m| self.result(*m, opponent) == result)
+    }
+    pub fn opponent_move(&self, letter: char) -> color_eyre::Result<Move> {
+        self.moves
+            .iter()
+            .position(|m| m.opponent == letter)
+            .map(Move)
+            .ok_or_eyre(format!("{letter:?} is no move of the opponent"))
+    }
+    pub fn player_move(&self, letter: char) -> color_eyre::Result<Move> {
+        self.moves
+            .iter()
+            .position(|m| m.player == letter)
+            .map(Move)
+            .ok_or_eyre(format!("{letter:?} is no move of the player"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_classic() {
+        let game = Game::classic();
+        assert!(game.beats(Move::ROCK, Move::SCISSORS));
+        assert!(game.beats(Move::PAPER, Move::ROCK));
+        assert!(game.beats(Move::SCISSORS, Move::PAPER));
+        assert!(!game.beats(Move::ROCK, Move::PAPER));
+        assert_eq!(game.player_move('Z').unwrap(), Move::SCISSORS);
+        assert_eq!(game.opponent_move('B').unwrap(), Move::PAPER);
+        assert_eq!(game.points(Move::SCISSORS), 3);
+    }
+    #[test]
+    fn test_rock_paper_scissors_lizard_spock() {
+        let game = Game::rock_paper_scissors_lizard_spock();
+        let m = |name| game.find(name).unwrap();
+        for (winner, loser) in [
+            ("scissors", "paper"),
+            ("paper", "rock"),
+            ("rock", "lizard"),
+            ("lizard", "spock"),
+            ("spock", "scissors"),
+            ("scissors", "lizard"),
+            ("lizard", "paper"),
+            ("paper", "spock"),
+            ("spock", "rock"),
+            ("rock", "scissors"),
+        ] {
+            assert!(game.beats(m(winner), m(loser)), "{winner} beats {loser}");
+            assert!(!game.beats(m(loser), m(winner)));
+        }
+        assert_eq!(game.player_move('V').unwrap(), m("rock"));
+    }
+    #[test]
+    fn test_invalid_definitions() {
+        assert!(Game::cyclic(&["a", "b", "c", "d"]).is_err());
+        assert!(Game::cyclic(&["a", "b", "a"]).is_err());
+        let definition = |name: &str, beats: &[&str]| MoveDefinition {
+            beats: beats.iter().map(|b| b.to_string()).collect(),
+            ..MoveDefinition::named(name)
+        };
+        assert!(Game::from_definition(vec![definition("a", &["b"]), definition("b", &[])]).is_ok());
+        assert!(
+            Game::from_definition(vec![definition("a", &["b"]), definition("b", &["a"])]).is_err()
+        );
+        assert!(
+            Game::from_definition(vec![definition("a", &["c"]), definition("b", &[])]).is_err()
+        );
+    }
+}
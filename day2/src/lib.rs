@@ -0,0 +1,364 @@
+use color_eyre::eyre::eyre;
+use common::{lines, Mode, RejectedLine, Solver};
+
+mod analysis;
+mod game;
+mod rules;
+
+pub use analysis::{Analysis, MonteCarlo};
+pub use game::{Game, Move, MoveDefinition};
+pub use rules::{OutcomePoints, Rules};
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum RoundResult {
+    Win,
+    Lose,
+    Draw,
+}
+impl TryFrom<char> for RoundResult {
+    type Error = color_eyre::Report;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'X' => Ok(Self::Lose),
+            'Y' => Ok(Self::Draw),
+            'Z' => Ok(Self::Win),
+            _ => Err(eyre!("{value:?} is no round result")),
+        }
+    }
+}
+
+/// How the second column of the strategy guide tells which move to play.
+pub trait Strategy {
+    fn player_move(
+        &self,
+        game: &Game,
+        opponent_move: Move,
+        column: char,
+    ) -> color_eyre::Result<Move>;
+}
+
+/// The second column is the move to play, like X for rock, Y for paper and Z for scissors.
+pub struct PlayMove;
+
+impl Strategy for PlayMove {
+    fn player_move(
+        &self,
+        game: &Game,
+        _opponent_move: Move,
+        column: char,
+    ) -> color_eyre::Result<Move> {
+        game.player_move(column)
+    }
+}
+
+/// The second column is how the round has to end: X to lose, Y to draw and Z to win.
+pub struct ReachResult;
+
+impl Strategy for ReachResult {
+    fn player_move(
+        &self,
+        game: &Game,
+        opponent_move: Move,
+        column: char,
+    ) -> color_eyre::Result<Move> {
+        let result = RoundResult::try_from(column)?;
+        game.answer_for(opponent_move, result).ok_or_else(|| {
+            eyre!(
+                "no move gets a {result:?} against {}",
+                game.name(opponent_move)
+            )
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Round {
+    player_move: Move,
+    opponent_move: Move,
+}
+
+impl Round {
+    pub fn new(opponent_move: Move, player_move: Move) -> Self {
+        Round {
+            player_move,
+            opponent_move,
+        }
+    }
+    pub fn result(&self, game: &Game) -> RoundResult {
+        game.result(self.player_move, self.opponent_move)
+    }
+    pub fn score(&self, game: &Game, rules: &Rules) -> i32 {
+        game.points(self.player_move) + rules.outcome_points(self.result(game))
+    }
+}
+
+/// A line of the strategy guide, before a strategy gave meaning to its second column.
+#[derive(Debug, Clone, Copy)]
+pub struct Entry {
+    /// Number of the line in the guide, starting at 1
+    line: usize,
+    /// The line as read, like `A Y`
+    text: [char; 3],
+    opponent_move: Move,
+    column: char,
+}
+
+impl Entry {
+    /// Reads line number `line` of two columns separated by a space, like `A Y`.
+    ///
+    /// The second column has to be a move of the player or a result of a round.
+    pub fn parse(text: &str, line: usize, game: &Game, rules: &Rules) -> color_eyre::Result<Self> {
+        let chars: Vec<char> = text.chars().collect();
+        if chars.len() != 3 || chars[1] != ' ' {
+            return Err(eyre!("expected two letters separated by a space"));
+        }
+        let (opponent, column) = match rules.opponent_column {
+            0 => (chars[0], chars[2]),
+            _ => (chars[2], chars[0]),
+        };
+        let opponent_move = game.opponent_move(opponent)?;
+        if game.player_move(column).is_err() && RoundResult::try_from(column).is_err() {
+            return Err(eyre!(
+                "{column:?} is neither a move of the player nor a round result"
+            ));
+        }
+        Ok(Self {
+            line,
+            text: [chars[0], chars[1], chars[2]],
+            opponent_move,
+            column,
+        })
+    }
+    pub fn line(&self) -> usize {
+        self.line
+    }
+    pub fn text(&self) -> String {
+        self.text.iter().collect()
+    }
+    pub fn round(&self, game: &Game, strategy: &dyn Strategy) -> color_eyre::Result<Round> {
+        let player_move = strategy.player_move(game, self.opponent_move, self.column)?;
+        Ok(Round::new(self.opponent_move, player_move))
+    }
+}
+
+pub struct StrategyGuide {
+    entries: Vec<Entry>,
+    game: Game,
+    rules: Rules,
+    mode: Mode,
+    rejected: Vec<RejectedLine>,
+}
+
+impl StrategyGuide {
+    /// Reads the guide, handling malformed lines according to `mode`.
+    pub fn parse_with_rules(input: &str, rules: Rules, mode: Mode) -> color_eyre::Result<Self> {
+        let game = rules.game()?;
+        let (entries, rejected) = lines::parse(input, mode, |text, line| {
+            Entry::parse(text, line, &game, &rules)
+        })?;
+        Ok(StrategyGuide {
+            entries,
+            game,
+            rules,
+            mode,
+            rejected,
+        })
+    }
+    pub fn game(&self) -> &Game {
+        &self.game
+    }
+    pub fn rules(&self) -> &Rules {
+        &self.rules
+    }
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+    /// Malformed lines skipped in lenient mode.
+    pub fn rejected(&self) -> &[RejectedLine] {
+        &self.rejected
+    }
+    /// The rounds played when following the guide with `strategy` and the lines it cannot
+    /// follow, which fail in strict mode.
+    fn play(&self, strategy: &dyn Strategy) -> color_eyre::Result<(Vec<Round>, Vec<RejectedLine>)> {
+        let mut rounds = vec![];
+        let mut skipped = vec![];
+        for entry in &self.entries {
+            match entry.round(&self.game, strategy) {
+                Ok(round) => rounds.push(round),
+                Err(error) => {
+                    lines::reject(self.mode, entry.line, &entry.text(), error, &mut skipped)?
+                }
+            }
+        }
+        Ok((rounds, skipped))
+    }
+    /// The rounds played when following the guide with `strategy`.
+    ///
+    /// In lenient mode, lines `strategy` cannot follow are left out.
+    pub fn rounds(&self, strategy: &dyn Strategy) -> color_eyre::Result<Vec<Round>> {
+        Ok(self.play(strategy)?.0)
+    }
+    /// Lines left out of the rounds of `strategy` in lenient mode.
+    pub fn skipped(&self, strategy: &dyn Strategy) -> Vec<RejectedLine> {
+        self.play(strategy)
+            .map(|(_, skipped)| skipped)
+            .unwrap_or_default()
+    }
+    pub fn total_score(&self, strategy: &dyn Strategy) -> color_eyre::Result<i32> {
+        let rounds = self.rounds(strategy)?;
+        Ok(rounds
+            .iter()
+            .map(|round| round.score(&self.game, &self.rules))
+            .sum())
+    }
+}
+
+impl Solver for StrategyGuide {
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> color_eyre::Result<Self> {
+        Self::parse_with(input, Mode::Strict)
+    }
+
+    fn parse_with(input: &str, mode: Mode) -> color_eyre::Result<Self> {
+        Self::parse_with_rules(input, Rules::default(), mode)
+    }
+
+    fn part1(&self) -> color_eyre::Result<i32> {
+        self.total_score(&PlayMove)
+    }
+
+    fn part2(&self) -> color_eyre::Result<i32> {
+        self.total_score(&ReachResult)
+    }
+
+    fn warnings(&self) -> Vec<String> {
+        let parts: [(u8, &dyn Strategy); 2] = [(1, &PlayMove), (2, &ReachResult)];
+        let skipped = parts.into_iter().flat_map(|(part, strategy)| {
+            self.skipped(strategy)
+                .into_iter()
+                .map(move |rejected| format!("part {part}: {rejected}"))
+        });
+        self.rejected
+            .iter()
+            .map(RejectedLine::to_string)
+            .chain(skipped)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "A Y\nB X\nC Z\n";
+
+    #[test]
+    fn test_example() {
+        let guide = StrategyGuide::parse(EXAMPLE).unwrap();
+        assert_eq!(guide.part1().unwrap(), 15);
+        assert_eq!(guide.part2().unwrap(), 12);
+    }
+    #[test]
+    fn test_score() {
+        let (game, rules) = (Game::classic(), Rules::default());
+        let score = |line| {
+            let entry = Entry::parse(line, 1, &game, &rules).unwrap();
+            entry.round(&game, &PlayMove).unwrap().score(&game, &rules)
+        };
+        // The opponent is in the first column, a loss is worth nothing and a draw 3.
+        assert_eq!(score("B X"), 1);
+        assert_eq!(score("A X"), 4);
+        assert_eq!(score("C X"), 7);
+        assert_eq!(score("A Z"), 3);
+    }
+    #[test]
+    fn test_custom_rules() {
+        let rules = Rules::from_toml("opponent_column = 1\n[outcome_points]\nwin = 10").unwrap();
+        let guide =
+            StrategyGuide::parse_with_rules("Y A\nX B\nZ C\n", rules, Mode::Strict).unwrap();
+        assert_eq!(guide.part1().unwrap(), 19);
+    }
+    #[test]
+    fn test_rock_paper_scissors_lizard_spock() {
+        let rules = Rules::from_toml(
+            "moves = [{ name = \"rock\" }, { name = \"spock\" }, { name = \"paper\" }, \
+             { name = \"lizard\" }, { name = \"scissors\" }]",
+        )
+        .unwrap();
+        // Rock crushes lizard, Spock smashes scissors and paper ties with paper.
+        let guide = StrategyGuide::parse_with_rules("A Y\nB Z\nC X\n", rules.clone(), Mode::Strict)
+            .unwrap();
+        assert_eq!(guide.part1().unwrap(), 4 + 5 + (3 + 3));
+        // Rock to draw, paper disproves Spock and rock loses against paper.
+        assert_eq!(guide.part2().unwrap(), (1 + 3) + (3 + 6) + 1);
+        // Rock to play is no result to reach.
+        let guide = StrategyGuide::parse_with_rules("A V\n", rules.clone(), Mode::Strict).unwrap();
+        assert_eq!(guide.part1().unwrap(), 1 + 3);
+        assert_eq!(
+            format!("{:#}", guide.part2().unwrap_err()),
+            "line 1: 'V' is no round result"
+        );
+        // Leniently, part 2 leaves out the line but still plays paper against rock and
+        // loses with the first move beaten by rock.
+        let guide = StrategyGuide::parse_with_rules("A V\nA X\n", rules, Mode::Lenient).unwrap();
+        assert_eq!(guide.part1().unwrap(), (1 + 3) + (3 + 6));
+        assert_eq!(guide.part2().unwrap(), 4);
+        assert_eq!(
+            guide.warnings(),
+            vec!["part 2: line 1: `A V` skipped ('V' is no round result)"]
+        );
+    }
+    #[test]
+    fn test_answer_for() {
+        for game in [Game::classic(), Game::rock_paper_scissors_lizard_spock()] {
+            for opponent in game.moves() {
+                for result in [RoundResult::Win, RoundResult::Lose, RoundResult::Draw] {
+                    let answer = game.answer_for(opponent, result).unwrap();
+                    assert_eq!(Round::new(opponent, answer).result(&game), result);
+                }
+            }
+        }
+    }
+    #[test]
+    fn test_invalid_rounds() {
+        let input = "A Y\nB W\nB  X\nD Z\n\nC Z\r\nA\tX\n";
+        assert_eq!(
+            format!("{:#}", StrategyGuide::parse(input).err().unwrap()),
+            "line 2: 'W' is neither a move of the player nor a round result"
+        );
+        let guide = StrategyGuide::parse_with(input, Mode::Lenient).unwrap();
+        assert_eq!(guide.part1().unwrap(), 8 + 6);
+        assert_eq!(
+            guide.rejected(),
+            [
+                RejectedLine {
+                    line: 2,
+                    text: "B W".to_string(),
+                    reason: "'W' is neither a move of the player nor a round result".to_string(),
+                },
+                RejectedLine {
+                    line: 3,
+                    text: "B  X".to_string(),
+                    reason: "expected two letters separated by a space".to_string(),
+                },
+                RejectedLine {
+                    line: 4,
+                    text: "D Z".to_string(),
+                    reason: "'D' is no move of the opponent".to_string(),
+                },
+                RejectedLine {
+                    line: 7,
+                    text: "A\tX".to_string(),
+                    reason: "expected two letters separated by a space".to_string(),
+                },
+            ]
+        );
+        assert_eq!(
+            guide.warnings()[3],
+            "line 7: `A\\tX` skipped (expected two letters separated by a space)"
+        );
+    }
+}
@@ -0,0 +1,135 @@
+use std::{collections::BTreeMap, path::Path};
+
+use color_eyre::eyre::{eyre, WrapErr};
+use serde::Deserialize;
+
+use crate::{Game, MoveDefinition, RoundResult};
+
+/// How rounds are scored, which game is played and how the columns of the strategy guide
+/// are laid out.
+///
+/// Every field is optional in the config file and falls back to the puzzle's rules.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+#[serde(default, deny_unknown_fields)]
+pub struct Rules {
+    /// Column with the opponent's move, 0 or 1, the other one is read by the strategy
+    pub opponent_column: usize,
+    /// Points for the shape the player chose by the name of the move, overriding the game's
+    pub shape_points: BTreeMap<String, i32>,
+    pub outcome_points: OutcomePoints,
+    /// Moves of the game, rock, paper and scissors when left out
+    pub moves: Vec<MoveDefinition>,
+}
+
+/// Points for how the round ended for the player.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(default, deny_unknown_fields)]
+pub struct OutcomePoints {
+    pub lose: i32,
+    pub draw: i32,
+    pub win: i32,
+}
+
+impl Default for OutcomePoints {
+    fn default() -> Self {
+        OutcomePoints {
+            lose: 0,
+            draw: 3,
+            win: 6,
+        }
+    }
+}
+
+impl Rules {
+    /// Reads rules from TOML, e.g. `opponent_column = 1` and `[outcome_points]` with `win = 10`.
+    pub fn from_toml(text: &str) -> color_eyre::Result<Self> {
+        let rules: Rules = toml::from_str(text)?;
+        if rules.opponent_column > 1 {
+            return Err(eyre!(
+                "the opponent column is {}, but there are only columns 0 and 1",
+                rules.opponent_column
+            ));
+        }
+        Ok(rules)
+    }
+    pub fn load(path: &Path) -> color_eyre::Result<Self> {
+        let text = std::fs::read_to_string(path)
+            .wrap_err_with(|| format!("failed to read rules {}", path.display()))?;
+        Self::from_toml(&text).wrap_err_with(|| format!("failed to parse rules {}", path.display()))
+    }
+    /// The game described by the moves, with the points of `shape_points`.
+    pub fn game(&self) -> color_eyre::Result<Game> {
+        let mut moves = match self.moves.is_empty() {
+            true => ["rock", "paper", "scissors"]
+                .map(MoveDefinition::named)
+                .to_vec(),
+            false => self.moves.clone(),
+        };
+        for (name, points) in &self.shape_points {
+            let definition = moves
+                .iter_mut()
+                .find(|m| &m.name == name)
+                .ok_or_else(|| eyre!("there is no move `{name}` to give points to"))?;
+            definition.points = Some(*points);
+        }
+        Game::from_definition(moves)
+    }
+    pub fn outcome_points(&self, result: RoundResult) -> i32 {
+        match result {
+            RoundResult::Lose => self.outcome_points.lose,
+            RoundResult::Draw => self.outcome_points.draw,
+            RoundResult::Win => self.outcome_points.win,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_from_toml() {
+        assert_eq!(Rules::from_toml("").unwrap(), Rules::default());
+        let rules = Rules::from_toml("opponent_column = 1\n[outcome_points]\nwin = 10\n").unwrap();
+        assert_eq!(rules.opponent_column, 1);
+        assert_eq!(rules.outcome_points(RoundResult::Win), 10);
+        assert_eq!(rules.outcome_points(RoundResult::Draw), 3);
+        assert!(Rules::from_toml("opponent_column = 2").is_err());
+        let rules = Rules::from_toml("[shape_points]\nspock = 4").unwrap();
+        assert!(rules.game().is_err());
+    }
+    #[test]
+    fn test_game() {
+        let rules = Rules::from_toml(
+            "[shape_points]\nscissors = 9\n\
+             [[moves]]\nname = \"rock\"\n\
+             [[moves]]\nname = \"spock\"\n\
+             [[moves]]\nname = \"paper\"\n\
+             [[moves]]\nname = \"lizard\"\n\
+             [[moves]]\nname = \"scissors\"\n",
+        )
+        .unwrap();
+        let game = rules.game().unwrap();
+        let m = |name| game.find(name).unwrap();
+        assert_eq!(game.points(m("scissors")), 9);
+        assert_eq!(game.points(m("spock")), 2);
+        for (winner, loser) in [
+            ("rock", "scissors"),
+            ("rock", "lizard"),
+            ("spock", "scissors"),
+            ("spock", "rock"),
+            ("paper", "spock"),
+            ("paper", "rock"),
+            ("lizard", "spock"),
+            ("lizard", "paper"),
+            ("scissors", "paper"),
+            ("scissors", "lizard"),
+        ] {
+            assert!(game.beats(m(winner), m(loser)), "{winner} beats {loser}");
+            assert!(
+                !game.beats(m(loser), m(winner)),
+                "{loser} loses to {winner}"
+            );
+        }
+    }
+}
@@ -0,0 +1,102 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    ops::{BitAnd, BitOr},
+};
+
+use crate::Item;
+
+/// A set of items, one bit per item type at the position of its score.
+#[derive(Default, Eq, PartialEq, Clone, Copy, Debug)]
+pub struct ItemSet(u64);
+
+impl ItemSet {
+    pub const EMPTY: ItemSet = ItemSet(0);
+    /// Every item type.
+    pub const ALL: ItemSet = ItemSet(((1 << 52) - 1) << 1);
+
+    pub fn insert(&mut self, item: Item) {
+        self.0 |= 1 << item.score();
+    }
+    pub fn contains(self, item: Item) -> bool {
+        self.0 & (1 << item.score()) != 0
+    }
+    pub fn intersection(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 & other.0)
+    }
+    pub fn union(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 | other.0)
+    }
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+    /// The items of the set, ordered by score.
+    pub fn iter(self) -> impl Iterator<Item = Item> {
+        let mut bits = self.0;
+        std::iter::from_fn(move || {
+            if bits == 0 {
+                return None;
+            }
+            let score = bits.trailing_zeros() as usize;
+            bits &= bits - 1;
+            Some(Item::from_score(score))
+        })
+    }
+}
+
+/// The items as letters, ordered by score.
+impl Display for ItemSet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.iter().try_for_each(|item| write!(f, "{item}"))
+    }
+}
+
+impl BitAnd for ItemSet {
+    type Output = ItemSet;
+
+    fn bitand(self, rhs: ItemSet) -> ItemSet {
+        self.intersection(rhs)
+    }
+}
+
+impl BitOr for ItemSet {
+    type Output = ItemSet;
+
+    fn bitor(self, rhs: ItemSet) -> ItemSet {
+        self.union(rhs)
+    }
+}
+
+impl FromIterator<Item> for ItemSet {
+    fn from_iter<T: IntoIterator<Item = Item>>(iter: T) -> Self {
+        let mut set = ItemSet::EMPTY;
+        for item in iter {
+            set.insert(item);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_set_operations() {
+        let set = |s: &str| -> ItemSet { s.bytes().map(|b| Item::try_from(b).unwrap()).collect() };
+        let (a, b) = (set("abcZ"), set("cZzq"));
+        assert_eq!(a & b, set("Zc"));
+        assert_eq!(a | b, set("abcqzZ"));
+        assert_eq!((a | b).len(), 6);
+        assert!(set("").is_empty());
+        assert_eq!(
+            b.iter().map(Item::score).collect::<Vec<_>>(),
+            vec![3, 17, 26, 52]
+        );
+        assert_eq!(b.to_string(), "cqzZ");
+        assert_eq!(ItemSet::ALL.len(), 52);
+        assert!(ItemSet::ALL.contains(Item::try_from(b'A').unwrap()));
+    }
+}
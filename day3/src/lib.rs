@@ -0,0 +1,240 @@
+use std::fmt::{self, Display, Formatter};
+
+use color_eyre::eyre::{eyre, WrapErr};
+use common::Solver;
+
+mod item_set;
+mod report;
+
+pub use item_set::ItemSet;
+pub use report::Report;
+
+#[derive(Hash, Eq, PartialEq, Clone, Copy, Debug)]
+pub struct Item(u8);
+
+impl Display for Item {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0 as char)
+    }
+}
+
+impl TryFrom<u8> for Item {
+    type Error = color_eyre::Report;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            b'a'..=b'z' | b'A'..=b'Z' => Ok(Item(value)),
+            _ => Err(eyre!(
+                "`{}` is not an item",
+                std::ascii::escape_default(value)
+            )),
+        }
+    }
+}
+impl Item {
+    /// The item worth `score`, which has to be in `1..=52`.
+    pub(crate) fn from_score(score: usize) -> Self {
+        match score {
+            1..=26 => Item(b'a' + (score - 1) as u8),
+            27..=52 => Item(b'A' + (score - 27) as u8),
+            _ => unreachable!(),
+        }
+    }
+    pub fn score(self) -> usize {
+        match self {
+            Item(b'a'..=b'z') => 1 + (self.0 - b'a') as usize,
+            Item(b'A'..=b'Z') => 27 + (self.0 - b'A') as usize,
+            _ => unreachable!(),
+        }
+    }
+}
+
+/// The only item of `items`, or an error naming what holds them.
+fn single(items: ItemSet, holder: impl FnOnce() -> String) -> color_eyre::Result<Item> {
+    match items.len() {
+        1 => Ok(items.iter().next().expect("the set has an item")),
+        0 => Err(eyre!("{} share no item", holder())),
+        _ => Err(eyre!("{} share more than one item", holder())),
+    }
+}
+
+/// The items of a rucksack, split into its two compartments.
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+pub struct Rucksack {
+    /// Items in the first and second half of the line
+    compartments: [ItemSet; 2],
+    /// Number of items, counting repeated ones
+    len: usize,
+}
+
+impl Rucksack {
+    /// Reads a line of items, every byte has to be a letter.
+    pub fn parse(line: &str) -> color_eyre::Result<Self> {
+        let len = line.len();
+        let mut compartments = [ItemSet::EMPTY; 2];
+        for (position, byte) in line.bytes().enumerate() {
+            let item = Item::try_from(byte).wrap_err_with(|| format!("column {}", position + 1))?;
+            compartments[(position >= len / 2) as usize].insert(item);
+        }
+        Ok(Rucksack { compartments, len })
+    }
+    pub fn compartments(&self) -> [ItemSet; 2] {
+        self.compartments
+    }
+    /// Every item type in the rucksack.
+    pub fn items(&self) -> ItemSet {
+        self.compartments[0] | self.compartments[1]
+    }
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    /// The item packed into both compartments, the first and second half of the rucksack.
+    pub fn misplaced_item(&self) -> color_eyre::Result<Item> {
+        if !self.len.is_multiple_of(2) {
+            return Err(eyre!(
+                "a rucksack with {} items cannot be split into two compartments",
+                self.len
+            ));
+        }
+        single(self.compartments[0] & self.compartments[1], || {
+            "the compartments".to_string()
+        })
+    }
+}
+
+pub struct Rucksacks {
+    rucksacks: Vec<Rucksack>,
+}
+
+impl Rucksacks {
+    pub fn rucksacks(&self) -> &[Rucksack] {
+        &self.rucksacks
+    }
+    /// The items shared by every group of `group_size` consecutive elves, the last group
+    /// may be smaller.
+    pub fn group_items(&self, group_size: usize) -> impl Iterator<Item = ItemSet> + '_ {
+        self.rucksacks.chunks(group_size.max(1)).map(|group| {
+            group
+                .iter()
+                .fold(ItemSet::ALL, |common, rucksack| common & rucksack.items())
+        })
+    }
+    /// The badge of every group of `group_size` consecutive elves, the one item they all carry.
+    pub fn badges(&self, group_size: usize) -> color_eyre::Result<Vec<Item>> {
+        if group_size == 0 {
+            return Err(eyre!("groups need at least one elf"));
+        }
+        if !self.rucksacks.len().is_multiple_of(group_size) {
+            return Err(eyre!(
+                "{} rucksacks cannot be split into groups of {group_size}",
+                self.rucksacks.len()
+            ));
+        }
+        self.group_items(group_size)
+            .enumerate()
+            .map(|(index, common)| single(common, || format!("the elves of group {}", index + 1)))
+            .collect()
+    }
+}
+
+impl Solver for Rucksacks {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> color_eyre::Result<Self> {
+        let rucksacks = input
+            .lines()
+            .enumerate()
+            .map(|(index, line)| {
+                Rucksack::parse(line).wrap_err_with(|| format!("line {}", index + 1))
+            })
+            .collect::<color_eyre::Result<_>>()?;
+        Ok(Rucksacks { rucksacks })
+    }
+
+    fn part1(&self) -> color_eyre::Result<usize> {
+        self.rucksacks
+            .iter()
+            .enumerate()
+            .map(|(index, rucksack)| {
+                rucksack
+                    .misplaced_item()
+                    .map(Item::score)
+                    .wrap_err_with(|| format!("rucksack {}", index + 1))
+            })
+            .sum()
+    }
+
+    fn part2(&self) -> color_eyre::Result<usize> {
+        Ok(self.badges(3)?.into_iter().map(Item::score).sum())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw
+";
+
+    fn item(c: u8) -> Item {
+        Item::try_from(c).unwrap()
+    }
+
+    #[test]
+    fn test_example() {
+        let rucksacks = Rucksacks::parse(EXAMPLE).unwrap();
+        assert_eq!(rucksacks.part1().unwrap(), 157);
+        assert_eq!(rucksacks.part2().unwrap(), 70);
+        assert_eq!(rucksacks.badges(3).unwrap(), vec![item(b'r'), item(b'Z')]);
+    }
+    #[test]
+    fn test_group_sizes() {
+        let rucksacks = Rucksacks::parse("abc\nbxy\ncde\nxyz\n").unwrap();
+        assert_eq!(
+            rucksacks.badges(1).unwrap_err().to_string(),
+            "the elves of group 1 share more than one item"
+        );
+        assert!(rucksacks.badges(0).is_err());
+        assert!(rucksacks.badges(3).is_err());
+        assert_eq!(
+            rucksacks.badges(2).unwrap_err().to_string(),
+            "the elves of group 2 share no item"
+        );
+        assert_eq!(
+            rucksacks.badges(4).unwrap_err().to_string(),
+            "the elves of group 1 share no item"
+        );
+        let rucksacks = Rucksacks::parse("abc\nbcd\ncde\nxyc\n").unwrap();
+        assert_eq!(rucksacks.badges(4).unwrap(), vec![item(b'c')]);
+    }
+    #[test]
+    fn test_misplaced_item() {
+        let misplaced = |line| Rucksack::parse(line).unwrap().misplaced_item();
+        assert_eq!(misplaced("abca").unwrap(), item(b'a'));
+        assert!(misplaced("abc").is_err());
+        assert!(misplaced("abcd").is_err());
+        assert!(misplaced("abab").is_err());
+    }
+    #[test]
+    fn test_invalid_items() {
+        for (input, error) in [
+            ("abc\nab1c\n", "line 2: column 3: `1` is not an item"),
+            ("ab cd\n", "line 1: column 3: ` ` is not an item"),
+            ("abab\r\r\n", "line 1: column 5: `\\r` is not an item"),
+        ] {
+            assert_eq!(
+                format!("{:#}", Rucksacks::parse(input).err().unwrap()),
+                error
+            );
+        }
+    }
+}
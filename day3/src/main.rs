@@ -1,47 +1,16 @@
-use std::collections::{HashMap, HashSet};
+use common::Solver;
+use day3::{Report, Rucksacks};
 
-#[derive(Hash, Eq, PartialEq, Clone, Copy)]
-struct Item(u8);
+/// Elves sharing a badge.
+const GROUP_SIZE: usize = 3;
 
-impl TryFrom<u8> for Item {
-    type Error = color_eyre::Report;
-
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            b'a'..=b'z' | b'A'..=b'Z' => Ok(Item(value)),
-            _ => Err(color_eyre::eyre::eyre!("Value {}, is not valid", value)),
-        }
-    }
-}
-impl Item {
-    fn score(self) -> usize {
-        match self {
-            Item(b'a'..=b'z') => 1 + (self.0 - b'a') as usize,
-            Item(b'A'..=b'Z') => 27 + (self.0 - b'A') as usize,
-            _ => unreachable!(),
-        }
-    }
-}
-
-fn main() {
-    let mut sum: usize = 0;
-    let contents = std::fs::read_to_string("input.txt").unwrap();
-    let mut group = HashMap::new();
-
-    contents.lines().for_each(|line| {
-        for item in line
-            .bytes()
-            .filter_map(|i| Item::try_from(i).ok())
-            .collect::<HashSet<Item>>()
-        {
-            let counter = group.entry(item).or_insert(0);
-            *counter += 1;
-            if *counter == 3 {
-                sum += item.score();
-                group.clear();
-                break;
-            }
-        }
-    });
-    println!("{}", sum);
+fn main() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+    let contents = common::input::from_args(3)?;
+    let rucksacks = Rucksacks::parse(&contents)?;
+    // The report comes first to show what went wrong when a part fails.
+    print!("{}", Report::new(&rucksacks, GROUP_SIZE));
+    println!("Part 1: {}", rucksacks.part1()?);
+    println!("Part 2: {}", rucksacks.part2()?);
+    Ok(())
 }
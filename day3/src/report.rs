@@ -0,0 +1,111 @@
+use std::fmt::{self, Display, Formatter};
+
+use crate::{ItemSet, Rucksacks};
+
+/// The shared items of every rucksack and group for people to read, numbered from 1.
+///
+/// Rucksacks and groups without exactly one shared item are marked instead of failing.
+pub struct Report<'a> {
+    rucksacks: &'a Rucksacks,
+    group_size: usize,
+}
+
+impl<'a> Report<'a> {
+    pub fn new(rucksacks: &'a Rucksacks, group_size: usize) -> Self {
+        Report {
+            rucksacks,
+            group_size,
+        }
+    }
+}
+
+/// The shared items with their scores, like `p (16)`, and the score they contribute.
+fn shared(f: &mut Formatter<'_>, items: ItemSet) -> Result<usize, fmt::Error> {
+    if items.is_empty() {
+        write!(f, "nothing")?;
+    }
+    for (i, item) in items.iter().enumerate() {
+        let separator = if i == 0 { "" } else { ", " };
+        write!(f, "{separator}{item} ({})", item.score())?;
+    }
+    match items.len() {
+        1 => Ok(items.iter().map(|item| item.score()).sum()),
+        _ => {
+            write!(f, " !")?;
+            Ok(0)
+        }
+    }
+}
+
+impl Display for Report<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut total = 0;
+        for (index, rucksack) in self.rucksacks.rucksacks().iter().enumerate() {
+            let [first, second] = rucksack.compartments();
+            write!(f, "rucksack {}: {first} | {second} share ", index + 1)?;
+            let score = shared(f, first & second)?;
+            // Like part 1, a rucksack which cannot be split has no misplaced item.
+            match rucksack.len().is_multiple_of(2) {
+                true => total += score,
+                false => write!(f, ", {} items cannot be split evenly", rucksack.len())?,
+            }
+            writeln!(f)?;
+        }
+        writeln!(f, "sum of misplaced items: {total}")?;
+        let rucksacks = self.rucksacks.rucksacks().len();
+        let group_size = self.group_size.max(1);
+        let mut total = 0;
+        for (index, items) in self.rucksacks.group_items(group_size).enumerate() {
+            let first = index * group_size + 1;
+            let last = (first + group_size - 1).min(rucksacks);
+            write!(
+                f,
+                "group {} of rucksacks {first}..={last} shares ",
+                index + 1
+            )?;
+            let score = shared(f, items)?;
+            // Like part 2, an incomplete group has no badge.
+            match last - first + 1 < group_size {
+                true => write!(f, ", a group of only {}", last - first + 1)?,
+                false => total += score,
+            }
+            writeln!(f)?;
+        }
+        writeln!(f, "sum of badges: {total}")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use common::Solver;
+
+    use super::*;
+
+    #[test]
+    fn test_report() {
+        let rucksacks = Rucksacks::parse("abca\nxbyb\nbzq\n").unwrap();
+        assert_eq!(
+            Report::new(&rucksacks, 2).to_string(),
+            "rucksack 1: ab | ac share a (1)\n\
+             rucksack 2: bx | by share b (2)\n\
+             rucksack 3: b | qz share nothing !, 3 items cannot be split evenly\n\
+             sum of misplaced items: 3\n\
+             group 1 of rucksacks 1..=2 shares b (2)\n\
+             group 2 of rucksacks 3..=3 shares b (2), q (17), z (26) !, a group of only 1\n\
+             sum of badges: 2\n"
+        );
+    }
+    #[test]
+    fn test_totals_match_the_parts() {
+        let rucksacks = Rucksacks::parse("abcda\nxbyb\nbcde\n").unwrap();
+        assert!(rucksacks.part1().is_err());
+        assert!(rucksacks.badges(2).is_err());
+        let report = Report::new(&rucksacks, 2).to_string();
+        assert!(
+            report.contains("rucksack 1: ab | acd share a (1), 5 items cannot be split evenly\n")
+        );
+        assert!(report.contains("sum of misplaced items: 2\n"));
+        assert!(report.contains("shares b (2), c (3), d (4), e (5) !, a group of only 1\n"));
+        assert!(report.ends_with("sum of badges: 2\n"));
+    }
+}
@@ -0,0 +1,148 @@
+use std::{
+    cmp::{max, min},
+    fmt::{self, Display, Formatter},
+};
+
+use color_eyre::eyre::{eyre, WrapErr};
+
+/// The sections an elf has to clean, from `first` to `last` inclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Assignment {
+    first: usize,
+    last: usize,
+}
+
+/// Written like `2-4`, the way it is read.
+impl Display for Assignment {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.first, self.last)
+    }
+}
+
+impl TryFrom<&str> for Assignment {
+    type Error = color_eyre::Report;
+
+    /// Reads a range like `2-4`.
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let (first, last) = value
+            .split_once('-')
+            .ok_or_else(|| eyre!("`{value}` is no range of sections like `2-4`"))?;
+        let section = |s: &str| {
+            s.parse::<usize>()
+                .wrap_err_with(|| format!("`{s}` is no section"))
+        };
+        Self::new(section(first)?, section(last)?)
+    }
+}
+
+impl Assignment {
+    pub fn new(first: usize, last: usize) -> color_eyre::Result<Self> {
+        if first > last {
+            return Err(eyre!("the assignment {first}-{last} ends before it starts"));
+        }
+        Ok(Assignment { first, last })
+    }
+    pub fn first(&self) -> usize {
+        self.first
+    }
+    pub fn last(&self) -> usize {
+        self.last
+    }
+    /// Number of sections, never 0.
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        self.last - self.first + 1
+    }
+    pub fn contains(&self, other: &Self) -> bool {
+        self.first <= other.first && self.last >= other.last
+    }
+    /// Whether the two share at least one section.
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.first <= other.last && other.first <= self.last
+    }
+    /// Whether the two share some sections, but neither contains the other.
+    pub fn overlaps_partially(&self, other: &Self) -> bool {
+        self.overlaps(other) && !self.contains(other) && !other.contains(self)
+    }
+    /// The sections in both.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        self.overlaps(other).then(|| Assignment {
+            first: max(self.first, other.first),
+            last: min(self.last, other.last),
+        })
+    }
+    /// The sections in either, if they form one range because the two overlap or touch.
+    pub fn union(&self, other: &Self) -> Option<Self> {
+        let touching = self.first <= other.last.saturating_add(1)
+            && other.first <= self.last.saturating_add(1);
+        touching.then(|| Assignment {
+            first: min(self.first, other.first),
+            last: max(self.last, other.last),
+        })
+    }
+    /// The sections of `self` which are not in `other`, as up to two ranges in order.
+    pub fn difference(&self, other: &Self) -> Vec<Self> {
+        let Some(common) = self.intersection(other) else {
+            return vec![*self];
+        };
+        let mut rest = vec![];
+        if self.first < common.first {
+            rest.push(Assignment {
+                first: self.first,
+                last: common.first - 1,
+            });
+        }
+        if common.last < self.last {
+            rest.push(Assignment {
+                first: common.last + 1,
+                last: self.last,
+            });
+        }
+        rest
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn range(first: usize, last: usize) -> Assignment {
+        Assignment::new(first, last).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Assignment::try_from("2-4").unwrap(), range(2, 4));
+        assert_eq!(range(6, 6).len(), 1);
+        assert_eq!(range(2, 4).to_string(), "2-4");
+        for (input, error) in [
+            ("4-2", "the assignment 4-2 ends before it starts"),
+            ("24", "`24` is no range of sections like `2-4`"),
+            ("2-x", "`x` is no section: invalid digit found in string"),
+        ] {
+            assert_eq!(
+                format!("{:#}", Assignment::try_from(input).unwrap_err()),
+                error
+            );
+        }
+    }
+    #[test]
+    fn test_algebra() {
+        let (a, b) = (range(2, 6), range(4, 8));
+        assert!(a.overlaps(&b) && a.overlaps_partially(&b));
+        assert!(!range(2, 8).overlaps_partially(&range(3, 7)));
+        assert!(!range(2, 3).overlaps(&range(4, 5)));
+        assert_eq!(a.intersection(&b), Some(range(4, 6)));
+        assert_eq!(a.intersection(&range(7, 9)), None);
+        assert_eq!(a.union(&b), Some(range(2, 8)));
+        assert_eq!(range(2, 3).union(&range(4, 5)), Some(range(2, 5)));
+        assert_eq!(range(2, 3).union(&range(5, 5)), None);
+        assert_eq!(a.difference(&b), vec![range(2, 3)]);
+        assert_eq!(
+            range(2, 8).difference(&range(4, 5)),
+            vec![range(2, 3), range(6, 8)]
+        );
+        assert_eq!(range(4, 5).difference(&range(2, 8)), vec![]);
+        assert_eq!(a.difference(&range(8, 9)), vec![a]);
+    }
+}
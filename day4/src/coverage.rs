@@ -0,0 +1,116 @@
+use crate::Assignment;
+
+/// How many elves clean each section, from the first to the last section anyone cleans.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Coverage {
+    /// Consecutive ranges of sections with the number of elves cleaning each of them
+    segments: Vec<(Assignment, usize)>,
+}
+
+impl Coverage {
+    /// Sweeps over the starts and ends of `assignments` in order.
+    ///
+    /// Assignments end right after their last section, which may be past `usize::MAX`.
+    pub fn new<'a>(assignments: impl IntoIterator<Item = &'a Assignment>) -> Self {
+        let mut events: Vec<(u128, isize)> = assignments
+            .into_iter()
+            .flat_map(|a| [(a.first() as u128, 1), (a.last() as u128 + 1, -1)])
+            .collect();
+        events.sort_unstable();
+        let mut segments: Vec<(Assignment, usize)> = vec![];
+        let mut elves: isize = 0;
+        for (i, (section, change)) in events.iter().enumerate() {
+            elves += change;
+            let Some((next, _)) = events.get(i + 1) else {
+                break;
+            };
+            if next == section {
+                continue;
+            }
+            let range =
+                Assignment::new(*section as usize, (next - 1) as usize).expect("events are sorted");
+            match segments.last_mut() {
+                Some((last, count)) if *count == elves as usize => {
+                    *last = last.union(&range).expect("segments touch");
+                }
+                _ => segments.push((range, elves as usize)),
+            }
+        }
+        Coverage { segments }
+    }
+    pub fn segments(&self) -> &[(Assignment, usize)] {
+        &self.segments
+    }
+    /// Sections between the cleaned ones which nobody cleans.
+    pub fn uncovered(&self) -> Vec<Assignment> {
+        self.matching(|elves| elves == 0)
+    }
+    /// Sections cleaned by more than `k` elves.
+    pub fn more_than(&self, k: usize) -> Vec<Assignment> {
+        self.matching(|elves| elves > k)
+    }
+    /// The most elves cleaning the same section, 0 without any assignments.
+    pub fn max_concurrent(&self) -> usize {
+        self.segments
+            .iter()
+            .map(|(_, elves)| *elves)
+            .max()
+            .unwrap_or(0)
+    }
+    /// Ranges of sections whose number of elves satisfies `predicate`, joined when they touch.
+    fn matching(&self, predicate: impl Fn(usize) -> bool) -> Vec<Assignment> {
+        let mut ranges: Vec<Assignment> = vec![];
+        for (range, _) in self.segments.iter().filter(|(_, elves)| predicate(*elves)) {
+            match ranges.last_mut().and_then(|last| last.union(range)) {
+                Some(joined) => *ranges.last_mut().expect("joined with the last") = joined,
+                None => ranges.push(*range),
+            }
+        }
+        ranges
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn range(first: usize, last: usize) -> Assignment {
+        Assignment::new(first, last).unwrap()
+    }
+
+    #[test]
+    fn test_coverage() {
+        let assignments = [range(2, 4), range(3, 5), range(3, 3), range(8, 9)];
+        let coverage = Coverage::new(&assignments);
+        assert_eq!(
+            coverage.segments(),
+            [
+                (range(2, 2), 1),
+                (range(3, 3), 3),
+                (range(4, 4), 2),
+                (range(5, 5), 1),
+                (range(6, 7), 0),
+                (range(8, 9), 1),
+            ]
+        );
+        assert_eq!(coverage.uncovered(), vec![range(6, 7)]);
+        assert_eq!(coverage.more_than(1), vec![range(3, 4)]);
+        assert_eq!(coverage.max_concurrent(), 3);
+        assert!(coverage.more_than(3).is_empty());
+        assert_eq!(Coverage::new(&[]).max_concurrent(), 0);
+    }
+    #[test]
+    fn test_last_section() {
+        let assignments = [
+            range(1, usize::MAX),
+            range(2, 3),
+            range(usize::MAX, usize::MAX),
+        ];
+        let coverage = Coverage::new(&assignments);
+        assert_eq!(
+            coverage.more_than(1),
+            vec![range(2, 3), range(usize::MAX, usize::MAX)]
+        );
+        assert_eq!(coverage.max_concurrent(), 2);
+    }
+}
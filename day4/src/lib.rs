@@ -0,0 +1,162 @@
+use color_eyre::eyre::eyre;
+use common::{lines, Mode, RejectedLine, Solver};
+
+mod assignment;
+mod coverage;
+
+pub use assignment::Assignment;
+pub use coverage::Coverage;
+
+/// Two or more elves cleaning up together.
+pub struct Group {
+    assignments: Vec<Assignment>,
+}
+impl TryFrom<&str> for Group {
+    type Error = color_eyre::Report;
+
+    /// Reads assignments separated by commas, like `2-4,6-8`.
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let assignments = value
+            .split(',')
+            .map(Assignment::try_from)
+            .collect::<color_eyre::Result<Vec<_>>>()?;
+        if assignments.len() < 2 {
+            return Err(eyre!(
+                "`{value}` is not two or more assignments separated by commas"
+            ));
+        }
+        Ok(Self { assignments })
+    }
+}
+impl Group {
+    pub fn assignments(&self) -> &[Assignment] {
+        &self.assignments
+    }
+    /// Whether one of the elves cleans every section of all the others.
+    pub fn fully_contains(&self) -> bool {
+        self.assignments
+            .iter()
+            .any(|a| self.assignments.iter().all(|b| a.contains(b)))
+    }
+    /// Whether the elves clean some section twice.
+    pub fn overlaps(&self) -> bool {
+        Coverage::new(&self.assignments).max_concurrent() > 1
+    }
+    pub fn coverage(&self) -> Coverage {
+        Coverage::new(&self.assignments)
+    }
+}
+
+pub struct CleanupPlan {
+    groups: Vec<Group>,
+    rejected: Vec<RejectedLine>,
+}
+
+impl CleanupPlan {
+    pub fn groups(&self) -> &[Group] {
+        &self.groups
+    }
+    /// Malformed lines skipped in lenient mode.
+    pub fn rejected(&self) -> &[RejectedLine] {
+        &self.rejected
+    }
+    /// How many elves of all groups clean each section.
+    pub fn coverage(&self) -> Coverage {
+        Coverage::new(self.groups.iter().flat_map(Group::assignments))
+    }
+}
+
+impl Solver for CleanupPlan {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> color_eyre::Result<Self> {
+        Self::parse_with(input, Mode::Strict)
+    }
+
+    fn parse_with(input: &str, mode: Mode) -> color_eyre::Result<Self> {
+        let (groups, rejected) = lines::parse(input, mode, |text, _| Group::try_from(text))?;
+        Ok(CleanupPlan { groups, rejected })
+    }
+
+    fn part1(&self) -> color_eyre::Result<usize> {
+        Ok(self
+            .groups
+            .iter()
+            .filter(|group| group.fully_contains())
+            .count())
+    }
+
+    fn part2(&self) -> color_eyre::Result<usize> {
+        Ok(self.groups.iter().filter(|group| group.overlaps()).count())
+    }
+
+    fn warnings(&self) -> Vec<String> {
+        self.rejected.iter().map(RejectedLine::to_string).collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let plan =
+            CleanupPlan::parse("2-4,6-8\n2-3,4-5\n5-7,7-9\n2-8,3-7\n6-6,4-6\n2-6,4-8\n").unwrap();
+        assert_eq!(plan.part1().unwrap(), 2);
+        assert_eq!(plan.part2().unwrap(), 4);
+    }
+    #[test]
+    fn test_invalid_line() {
+        assert_eq!(
+            format!(
+                "{:#}",
+                CleanupPlan::parse("2-4,6-8\n2-3 4-5\n").err().unwrap()
+            ),
+            "line 2: `3 4-5` is no section: invalid digit found in string"
+        );
+        assert!(CleanupPlan::parse("2-4\n").is_err());
+    }
+    #[test]
+    fn test_larger_groups() {
+        let plan = CleanupPlan::parse("1-9,2-3,5-5\n1-2,3-4,5-6\n1-3,4-5,3-3\n").unwrap();
+        assert_eq!(plan.part1().unwrap(), 1);
+        assert_eq!(plan.part2().unwrap(), 2);
+        let coverage = plan.coverage();
+        assert_eq!(coverage.max_concurrent(), 5);
+        assert_eq!(
+            coverage.more_than(3),
+            vec![
+                Assignment::new(2, 3).unwrap(),
+                Assignment::new(5, 5).unwrap()
+            ]
+        );
+        assert!(coverage.uncovered().is_empty());
+        let plan = CleanupPlan::parse(&format!("1-{},2-3\n", usize::MAX)).unwrap();
+        assert_eq!(plan.part2().unwrap(), 1);
+    }
+    #[test]
+    fn test_lenient() {
+        let input = "2-4,6-8\n2-3 4-5\n\n5-7,9-7\n2-8,3-7\n2-x,1-1\n";
+        let plan = CleanupPlan::parse_with(input, Mode::Lenient).unwrap();
+        assert_eq!(plan.groups().len(), 2);
+        assert_eq!(plan.part1().unwrap(), 1);
+        assert_eq!(
+            plan.rejected()
+                .iter()
+                .map(|rejected| (rejected.line, rejected.reason.as_str()))
+                .collect::<Vec<_>>(),
+            vec![
+                (2, "`3 4-5` is no section: invalid digit found in string"),
+                (4, "the assignment 9-7 ends before it starts"),
+                (6, "`x` is no section: invalid digit found in string"),
+            ]
+        );
+        assert_eq!(
+            plan.warnings()[1],
+            "line 4: `5-7,9-7` skipped (the assignment 9-7 ends before it starts)"
+        );
+        assert!(CleanupPlan::parse(input).is_err());
+    }
+}
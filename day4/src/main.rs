@@ -1,62 +1,32 @@
-#[derive(Debug)]
-struct Assignment {
-    fist: usize,
-    last: usize,
-}
-struct Group {
-    first: Assignment,
-    second: Assignment,
-}
-impl TryFrom<&str> for Group {
-    type Error = color_eyre::Report;
-
-    fn try_from(value: &str) -> Result<Self, Self::Error> {
-        let (first, second) = value
-            .split_once(',')
-            .ok_or_else(|| color_eyre::eyre::eyre!("Invalid input"))?;
-        let first = Assignment::try_from(first)?;
-        let second = Assignment::try_from(second)?;
-        return Ok(Self { first, second });
-    }
-}
-impl Group {
-    fn overlaps(&self) -> bool {
-        return self.first.contains(&self.second) || self.second.contains(&self.first);
-    }
-}
-
-impl TryFrom<&str> for Assignment {
-    type Error = color_eyre::Report;
-
-    fn try_from(value: &str) -> Result<Self, Self::Error> {
-        let (first, second) = value
-            .split_once('-')
-            .ok_or_else(|| color_eyre::eyre::eyre!("Invalid input"))?;
-        return Ok(Self {
-            fist: first.parse()?,
-            last: second.parse()?,
-        });
-    }
-}
-
-impl Assignment {
-    fn contains(&self, other: &Self) -> bool {
-        return self.fist <= other.fist && self.last >= other.last;
-    }
-}
+use common::Solver;
+use day4::{Assignment, CleanupPlan};
 
-fn main() {
-    let contents = std::fs::read_to_string("input.txt").unwrap();
-    let sum = contents
-        .lines()
-        .map(|l| {
-            let group = Group::try_from(l).unwrap();
+/// Elves which may clean the same section before it is reported.
+const CREW_LIMIT: usize = 2;
 
-            if group.overlaps() {
-                return 1;
-            }
-            return 0;
-        })
-        .sum::<usize>();
-    println!("{:?}", sum)
+fn main() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+    let contents = common::input::from_args(4)?;
+    let plan = CleanupPlan::parse(&contents)?;
+    println!("Part 1: {}", plan.part1()?);
+    println!("Part 2: {}", plan.part2()?);
+    let coverage = plan.coverage();
+    let list = |ranges: Vec<Assignment>| match ranges.is_empty() {
+        true => "none".to_string(),
+        false => ranges
+            .iter()
+            .map(Assignment::to_string)
+            .collect::<Vec<_>>()
+            .join(", "),
+    };
+    println!("Sections nobody cleans: {}", list(coverage.uncovered()));
+    println!(
+        "Sections cleaned by more than {CREW_LIMIT} elves: {}",
+        list(coverage.more_than(CREW_LIMIT))
+    );
+    println!(
+        "Most elves cleaning one section: {}",
+        coverage.max_concurrent()
+    );
+    Ok(())
 }
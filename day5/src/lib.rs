@@ -0,0 +1,349 @@
+use color_eyre::eyre::{eyre, WrapErr};
+use common::{
+    parse::{self, IResult},
+    Solver,
+};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{anychar, char, digit1, line_ending, newline, space1, u32},
+    combinator::{cut, eof, map, opt},
+    error::context,
+    multi::{many0, many1},
+    sequence::{delimited, preceded, tuple},
+};
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Cargo(char);
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Ship {
+    cargo: Vec<Vec<Cargo>>,
+}
+
+impl Ship {
+    fn from_rows(mut rows: Vec<Vec<Option<Cargo>>>) -> Ship {
+        let mut cargo: Vec<Vec<Cargo>> = vec![vec![]; rows[0].len()];
+        if rows.is_empty() {
+            return Ship { cargo };
+        }
+        rows.reverse();
+
+        rows.iter().for_each(|row| {
+            row.iter().enumerate().for_each(|(index, container)| {
+                if cargo.get(index).is_none() {
+                    cargo.insert(index, vec![]);
+                }
+                if let Some(container) = container {
+                    cargo[index].push(*container);
+                }
+            })
+        });
+        Ship { cargo }
+    }
+    /// Moves the crates of `instruction` with `crane`, stacks are numbered from 1.
+    pub fn apply(
+        &mut self,
+        instruction: &Instruction,
+        crane: CraneModel,
+    ) -> color_eyre::Result<()> {
+        let stacks = self.cargo.len();
+        let stack = |number: usize| {
+            number
+                .checked_sub(1)
+                .filter(|index| *index < stacks)
+                .ok_or_else(|| eyre!("there is no stack {number}, only 1 to {stacks}"))
+        };
+        let (from, to) = (stack(instruction.from)?, stack(instruction.to)?);
+        let available = self.cargo[from].len();
+        if available < instruction.quantity {
+            return Err(eyre!(
+                "stack {} has only {available} of the {} crates to move",
+                instruction.from,
+                instruction.quantity
+            ));
+        }
+        let capacity = crane.capacity(instruction.quantity)?;
+        let mut remaining = instruction.quantity;
+        while remaining > 0 {
+            let lift = remaining.min(capacity);
+            let height = self.cargo[from].len();
+            let crates = self.cargo[from].split_off(height - lift);
+            self.cargo[to].extend(crates);
+            remaining -= lift;
+        }
+        Ok(())
+    }
+    pub fn top_crates(&self) -> String {
+        self.cargo
+            .iter()
+            .filter_map(|row| {
+                if let Some(container) = row.last() {
+                    return Some(container.0);
+                }
+                None
+            })
+            .collect()
+    }
+}
+
+/// How many crates a crane lifts at once. Lifted crates keep their order.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum CraneModel {
+    /// The CrateMover 9000, moving one crate at a time
+    SingleCrate,
+    /// The CrateMover 9001, moving all crates of an instruction at once
+    Block,
+    /// A crane lifting up to this many crates at once
+    MaxPerLift(usize),
+}
+
+impl CraneModel {
+    /// Crates lifted at once when moving `quantity` of them.
+    fn capacity(self, quantity: usize) -> color_eyre::Result<usize> {
+        match self {
+            CraneModel::SingleCrate => Ok(1),
+            CraneModel::Block => Ok(quantity.max(1)),
+            CraneModel::MaxPerLift(0) => Err(eyre!("a crane has to lift at least one crate")),
+            CraneModel::MaxPerLift(k) => Ok(k),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Instruction {
+    from: usize,
+    to: usize,
+    quantity: usize,
+}
+
+fn parse_ship_with_instructions(input: &str) -> IResult<&str, (Ship, Vec<Instruction>)> {
+    let (rest, ship) = parse_ship(input)?;
+    let (rest, _) = context("an empty line", many1(line_ending))(rest)?;
+    let (rest, instructions) = context(
+        "`move <n> from <n> to <n>`",
+        parse::many_to_end(parse_instruction),
+    )(rest)?;
+    Ok((rest, (ship, instructions)))
+}
+
+fn parse_number(input: &str) -> IResult<&str, usize> {
+    map(u32, |n| n as usize)(input)
+}
+
+fn parse_instruction(input: &str) -> IResult<&str, Instruction> {
+    let (rest, _) = tag("move")(input)?;
+    let (rest, (quantity, from, to)) = context(
+        "`move <n> from <n> to <n>`",
+        cut(tuple((
+            preceded(space1, parse_number),
+            preceded(tuple((space1, tag("from"), space1)), parse_number),
+            preceded(tuple((space1, tag("to"), space1)), parse_number),
+        ))),
+    )(rest)?;
+    let (rest, _) = context("end of line", cut(alt((line_ending, eof))))(rest)?;
+    Ok((rest, Instruction { from, to, quantity }))
+}
+
+fn parse_empty_spot(input: &str) -> IResult<&str, Option<Cargo>> {
+    match tuple((char(' '), char(' '), char(' ')))(input) {
+        Ok((rest, _)) => Ok((rest, None)),
+        Err(e) => Err(e),
+    }
+}
+fn parse_cargo(input: &str) -> IResult<&str, Option<Cargo>> {
+    let mut parser = delimited(char('['), anychar, char(']'));
+
+    let result = parser(input);
+    match result {
+        Ok((rest, cargo)) => Ok((rest, Some(Cargo(cargo)))),
+        Err(e) => Err(e),
+    }
+}
+fn parse_cargo_line(input: &str) -> IResult<&str, Vec<Option<Cargo>>> {
+    match many0(tuple((
+        alt((parse_cargo, parse_empty_spot)),
+        opt(char(' ')),
+    )))(input)
+    {
+        Ok((rest, cargo)) => match newline(rest) {
+            Ok((rest, _)) => {
+                let cargo = cargo.iter().copied().map(|(cargo, _)| cargo).collect();
+                Ok((rest, cargo))
+            }
+            Err(e) => Err(e),
+        },
+        Err(e) => Err(e),
+    }
+}
+
+fn parse_ship_hull(input: &str) -> IResult<&str, ()> {
+    match context("`[<crate>]` or stack numbers", many1(alt((space1, digit1))))(input) {
+        Ok((rest, _)) => {
+            let (rest, _) = context("end of line", newline)(rest)?;
+            Ok((rest, ()))
+        }
+        Err(e) => Err(e),
+    }
+}
+
+fn parse_ship(input: &str) -> IResult<&str, Ship> {
+    match tuple((many1(parse_cargo_line), (parse_ship_hull)))(input) {
+        Ok((rest, (cargo, _))) => Ok((rest, Ship::from_rows(cargo))),
+        Err(e) => Err(e),
+    }
+}
+
+pub struct Rearrangement {
+    ship: Ship,
+    instructions: Vec<Instruction>,
+}
+
+impl Rearrangement {
+    pub fn ship(&self) -> &Ship {
+        &self.ship
+    }
+    pub fn instructions(&self) -> &[Instruction] {
+        &self.instructions
+    }
+    /// The ship after following every instruction with `crane`.
+    pub fn rearrange(&self, crane: CraneModel) -> color_eyre::Result<Ship> {
+        let mut ship = self.ship.clone();
+        for (index, instruction) in self.instructions.iter().enumerate() {
+            ship.apply(instruction, crane)
+                .wrap_err_with(|| format!("instruction {}", index + 1))?;
+        }
+        Ok(ship)
+    }
+}
+
+impl Solver for Rearrangement {
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse(input: &str) -> color_eyre::Result<Self> {
+        let (ship, instructions) = parse::finish(input, parse_ship_with_instructions)
+            .wrap_err("failed to parse the rearrangement procedure")?;
+        Ok(Rearrangement { ship, instructions })
+    }
+
+    fn part1(&self) -> color_eyre::Result<String> {
+        Ok(self.rearrange(CraneModel::SingleCrate)?.top_crates())
+    }
+
+    fn part2(&self) -> color_eyre::Result<String> {
+        Ok(self.rearrange(CraneModel::Block)?.top_crates())
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn test() {
+        assert_eq!(parse_cargo("[D]"), Ok(("", Some(Cargo('D')))));
+    }
+    #[test]
+    fn test_empty_spot() {
+        assert_eq!(parse_empty_spot("   "), Ok(("", None)));
+    }
+    #[test]
+    fn test_cargo_line() {
+        assert_eq!(
+            parse_cargo_line("[D]     [C]\n"),
+            Ok(("", vec![Some(Cargo('D')), None, Some(Cargo('C'))]))
+        );
+        assert_eq!(
+            parse_cargo_line("    [D]    \n"),
+            Ok(("", vec![None, Some(Cargo('D')), None]))
+        );
+        assert_eq!(
+            parse_cargo_line("[N] [C]    \n"),
+            Ok(("", vec![Some(Cargo('N')), Some(Cargo('C')), None]))
+        );
+    }
+    #[test]
+    fn check_line_with_numbers() {
+        assert_eq!(parse_ship_hull(" 1   2   3 \n"), Ok(("", ())));
+    }
+
+    #[test]
+    fn test_parse_ship() {
+        //assert_eq!(
+        //     parse_ship("    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n"),
+        //     Ok((
+        //         "",
+        //         Ship {
+        //             cargo: vec![
+        //                 vec![None, Some(Cargo('D')), None],
+        //                 vec![Some(Cargo('N')), Some(Cargo('C')), None],
+        //                 vec![Some(Cargo('Z')), Some(Cargo('M')), Some(Cargo('P'))]
+        //             ]
+        //         }
+        //     ))
+        // );
+    }
+    #[test]
+    fn test_parse_instruction() {
+        assert_eq!(
+            parse_instruction("move 1 from 2 to 3\n"),
+            Ok((
+                "",
+                Instruction {
+                    from: 2,
+                    to: 3,
+                    quantity: 1
+                }
+            ))
+        );
+        assert_eq!(
+            parse_instruction("move 1 from 2 to 3"),
+            Ok((
+                "",
+                Instruction {
+                    from: 2,
+                    to: 3,
+                    quantity: 1
+                }
+            ))
+        );
+    }
+    #[test]
+    fn test_crane_models() {
+        let rearrangement = Rearrangement::parse(
+            "    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n\n\
+             move 1 from 2 to 1\nmove 3 from 1 to 3\nmove 2 from 2 to 1\nmove 1 from 1 to 2\n",
+        )
+        .unwrap();
+        assert_eq!(rearrangement.part1().unwrap(), "CMZ");
+        assert_eq!(rearrangement.part2().unwrap(), "MCD");
+        let top = |crane| rearrangement.rearrange(crane).unwrap().top_crates();
+        assert_eq!(top(CraneModel::MaxPerLift(1)), "CMZ");
+        assert_eq!(top(CraneModel::MaxPerLift(3)), "MCD");
+        // Moving `D`, `N` and `Z` onto `P` two at a time puts `Z` on top.
+        assert_eq!(top(CraneModel::MaxPerLift(2)), "MCZ");
+        assert!(rearrangement.rearrange(CraneModel::MaxPerLift(0)).is_err());
+    }
+    #[test]
+    fn test_invalid_moves() {
+        let mut ship = Ship::from_rows(vec![vec![Some(Cargo('A')), None]]);
+        let instruction = |quantity, from, to| Instruction { from, to, quantity };
+        assert_eq!(
+            ship.apply(&instruction(2, 1, 2), CraneModel::Block)
+                .unwrap_err()
+                .to_string(),
+            "stack 1 has only 1 of the 2 crates to move"
+        );
+        assert_eq!(
+            ship.apply(&instruction(1, 1, 3), CraneModel::Block)
+                .unwrap_err()
+                .to_string(),
+            "there is no stack 3, only 1 to 2"
+        );
+        ship.apply(&instruction(1, 1, 2), CraneModel::SingleCrate)
+            .unwrap();
+        assert_eq!(ship.top_crates(), "A");
+    }
+}
@@ -0,0 +1,58 @@
+use std::collections::HashSet;
+
+use color_eyre::eyre::OptionExt;
+use common::Solver;
+
+const PACKET_MARKER_LENGTH: usize = 4;
+const MESSAGE_MARKER_LENGTH: usize = 14;
+
+pub struct Datastream(String);
+
+impl Solver for Datastream {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> color_eyre::Result<Self> {
+        Ok(Datastream(input.trim_end().to_string()))
+    }
+
+    fn part1(&self) -> color_eyre::Result<usize> {
+        find_marker(&self.0, PACKET_MARKER_LENGTH)
+            .ok_or_eyre("no start-of-packet marker found in datastream")
+    }
+
+    fn part2(&self) -> color_eyre::Result<usize> {
+        find_marker(&self.0, MESSAGE_MARKER_LENGTH)
+            .ok_or_eyre("no start-of-message marker found in datastream")
+    }
+}
+
+/// Number of characters read until the last `length` characters are all different.
+///
+/// A marker needs at least one character, so there is none of length 0.
+pub fn find_marker(line: &str, length: usize) -> Option<usize> {
+    if length == 0 {
+        return None;
+    }
+    let chars: Vec<char> = line.chars().collect();
+    chars
+        .windows(length)
+        .position(|window| window.iter().collect::<HashSet<_>>().len() == length)
+        .map(|i| i + length)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_find_marker() {
+        let line = "mjqjpqmgbljsphdztnvjfqwrcgsmlb";
+        assert_eq!(find_marker(line, PACKET_MARKER_LENGTH), Some(7));
+        assert_eq!(find_marker(line, MESSAGE_MARKER_LENGTH), Some(19));
+        assert_eq!(find_marker(line, 1), Some(1));
+        assert_eq!(find_marker(line, 0), None);
+        assert_eq!(find_marker("", 0), None);
+        assert_eq!(find_marker("aab", 3), None);
+    }
+}
@@ -0,0 +1,96 @@
+use std::collections::HashSet;
+
+use color_eyre::eyre::{eyre, OptionExt};
+use common::Solver;
+use geometry::{Direction, Grid, Point};
+
+/// Heights of the trees in the grid.
+pub struct Forest {
+    grid: Grid<u8>,
+}
+
+impl Solver for Forest {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> color_eyre::Result<Self> {
+        let grid = Grid::parse(input, |c| {
+            c.to_digit(10)
+                .map(|height| height as u8)
+                .ok_or_else(|| eyre!("invalid tree height {c:?}"))
+        })?;
+        Ok(Forest { grid })
+    }
+
+    fn part1(&self) -> color_eyre::Result<usize> {
+        let grid = &self.grid;
+        if grid.width() < 3 || grid.height() < 3 {
+            return Ok(grid.width() * grid.height());
+        }
+        let mut visible: HashSet<Point<usize>> = HashSet::new();
+        for y in 1..grid.height() - 1 {
+            get_visible(grid.row(y).iter().collect())
+                .into_iter()
+                .for_each(|x| {
+                    visible.insert(Point::new(x, y));
+                });
+        }
+
+        for x in 1..grid.width() - 1 {
+            get_visible(grid.column(x).collect())
+                .into_iter()
+                .for_each(|y| {
+                    visible.insert(Point::new(x, y));
+                })
+        }
+
+        Ok((grid.width() + grid.height() - 2) * 2 + visible.len())
+    }
+
+    fn part2(&self) -> color_eyre::Result<usize> {
+        self.grid
+            .indexed_iter()
+            .map(|(tree, _)| scenic_score(&self.grid, tree))
+            .max()
+            .ok_or_eyre("the forest is empty")
+    }
+}
+
+/// Product of the viewing distances in all four directions from a tree.
+fn scenic_score(grid: &Grid<u8>, tree: Point<usize>) -> usize {
+    let height = grid[tree];
+    Direction::ALL
+        .into_iter()
+        .map(|direction| {
+            let mut distance = 0;
+            let mut current = tree;
+            while let Some(next) = current.step(direction).filter(|p| grid.contains(*p)) {
+                distance += 1;
+                if grid[next] >= height {
+                    break;
+                }
+                current = next;
+            }
+            distance
+        })
+        .product()
+}
+
+fn get_visible(list: Vec<&u8>) -> Vec<usize> {
+    let mut visible = vec![];
+    let mut first = list[0];
+    let mut last = list[list.len() - 1];
+    for (i, tree) in list.iter().enumerate().take(list.len() - 1).skip(1) {
+        if first < *tree {
+            first = tree;
+            visible.push(i);
+        }
+    }
+    for (i, tree) in list.iter().enumerate().take(list.len() - 1).skip(1).rev() {
+        if last < *tree {
+            last = tree;
+            visible.push(i);
+        }
+    }
+    visible
+}
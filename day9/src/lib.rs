@@ -0,0 +1,133 @@
+use color_eyre::eyre::WrapErr;
+use common::{
+    parse::{self, IResult},
+    Solver,
+};
+use geometry::{Direction, Point};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::line_ending,
+    combinator::{cut, eof},
+    error::context,
+};
+use std::collections::HashSet;
+
+mod visualization;
+
+pub use visualization::{start, RopeAnimation};
+
+pub type Knot = Point<i32>;
+
+#[derive(Debug)]
+pub struct Bridge {
+    head: Knot,
+    knots: Vec<Knot>,
+    visited_tail: HashSet<Knot>,
+}
+
+impl Bridge {
+    /// A rope made of a head followed by `knots` further knots.
+    pub fn new(knots: usize) -> Self {
+        let mut visited_tail = HashSet::new();
+        visited_tail.insert(Knot::default());
+        Bridge {
+            head: Knot::default(),
+            knots: vec![Knot::default(); knots],
+            visited_tail,
+        }
+    }
+    pub fn apply(&mut self, direction: Direction) {
+        self.head += Knot::from(direction);
+        let mut current = self.head;
+        self.knots.iter_mut().for_each(|k| {
+            follow(k, current);
+            current = *k;
+        });
+        self.visited_tail.insert(current);
+    }
+}
+/// Moves `knot` one step towards `other` unless they are still touching.
+fn follow(knot: &mut Knot, other: Knot) {
+    let diff = other - *knot;
+    if diff.x.abs() > 1 || diff.y.abs() > 1 {
+        *knot += Knot::new(diff.x.signum(), diff.y.signum());
+    }
+}
+
+pub struct Motions {
+    motions: Vec<(Direction, u32)>,
+}
+
+impl Motions {
+    pub fn motions(&self) -> &[(Direction, u32)] {
+        &self.motions
+    }
+    /// Single steps of the head, one per moved square.
+    pub fn steps(&self) -> impl Iterator<Item = Direction> + '_ {
+        self.motions
+            .iter()
+            .flat_map(|(direction, amount)| (0..*amount).map(|_| *direction))
+    }
+}
+
+impl Solver for Motions {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> color_eyre::Result<Self> {
+        let motions = parse::finish(input, parse_lines).wrap_err("failed to parse the motions")?;
+        Ok(Motions { motions })
+    }
+
+    fn part1(&self) -> color_eyre::Result<usize> {
+        Ok(self.visited_by_tail(1))
+    }
+
+    fn part2(&self) -> color_eyre::Result<usize> {
+        Ok(self.visited_by_tail(9))
+    }
+}
+
+impl Motions {
+    fn visited_by_tail(&self, knots: usize) -> usize {
+        let mut bridge = Bridge::new(knots);
+        self.steps().for_each(|direction| {
+            bridge.apply(direction);
+        });
+        bridge.visited_tail.len()
+    }
+}
+
+fn parse_direction(input: &str) -> IResult<&str, Direction> {
+    use Direction::*;
+
+    alt((tag("R"), tag("L"), tag("U"), tag("D")))(input).map(|(input, direction)| match direction {
+        "R" => (input, Right),
+        "L" => (input, Left),
+        "U" => (input, Up),
+        "D" => (input, Down),
+        _ => unreachable!(),
+    })
+}
+fn parse_number(input: &str) -> IResult<&str, u32> {
+    use nom::character::complete::digit1;
+    use nom::combinator::map_res;
+
+    map_res(digit1, |s: &str| s.parse::<u32>())(input)
+}
+fn parse_line(input: &str) -> IResult<&str, (Direction, u32)> {
+    use nom::sequence::tuple;
+
+    let (input, direction) = context("`R`, `L`, `U` or `D`", parse_direction)(input)?;
+    let (input, (_, amount)) = context(
+        "`<direction> <steps>`",
+        cut(tuple((tag(" "), parse_number))),
+    )(input)?;
+    let (input, _) = context("end of line", cut(alt((line_ending, eof))))(input)?;
+    Ok((input, (direction, amount)))
+}
+
+fn parse_lines(input: &str) -> IResult<&str, Vec<(Direction, u32)>> {
+    parse::many_to_end(parse_line)(input)
+}
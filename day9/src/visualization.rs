@@ -0,0 +1,76 @@
+use common::Solver;
+use geometry::{Direction, Point};
+use visualize::{Color, Frame, Visualize};
+use wasm_bindgen::{prelude::wasm_bindgen, JsValue};
+
+use crate::{Bridge, Knot, Motions};
+
+const VISITED: Color = [0.3, 0.3, 0.3, 1.0];
+const KNOT: Color = [1.0, 1.0, 1.0, 1.0];
+const HEAD: Color = [1.0, 0.0, 0.0, 1.0];
+
+/// A rope following the motions of its head, one step per moved square.
+pub struct RopeAnimation {
+    bridge: Bridge,
+    steps: Vec<Direction>,
+    next: usize,
+    min: Knot,
+    max: Knot,
+}
+
+impl RopeAnimation {
+    pub fn new(motions: &Motions, knots: usize) -> Self {
+        let steps: Vec<Direction> = motions.steps().collect();
+        // The knots never leave the area covered by the head.
+        let (mut min, mut max, mut head) = (Knot::default(), Knot::default(), Knot::default());
+        for direction in &steps {
+            head += Knot::from(*direction);
+            min = Knot::new(min.x.min(head.x), min.y.min(head.y));
+            max = Knot::new(max.x.max(head.x), max.y.max(head.y));
+        }
+        RopeAnimation {
+            bridge: Bridge::new(knots),
+            steps,
+            next: 0,
+            min,
+            max,
+        }
+    }
+    fn cell(&self, knot: Knot) -> Point<usize> {
+        let offset = knot - self.min;
+        Point::new(offset.x as usize, offset.y as usize)
+    }
+}
+
+impl Visualize for RopeAnimation {
+    fn size(&self) -> (usize, usize) {
+        let size = self.max - self.min;
+        (size.x as usize + 1, size.y as usize + 1)
+    }
+
+    fn step(&mut self) -> bool {
+        let Some(direction) = self.steps.get(self.next) else {
+            return false;
+        };
+        self.bridge.apply(*direction);
+        self.next += 1;
+        self.next < self.steps.len()
+    }
+
+    fn draw(&self, frame: &mut Frame) {
+        for knot in &self.bridge.visited_tail {
+            frame.paint(self.cell(*knot), VISITED);
+        }
+        for knot in &self.bridge.knots {
+            frame.paint(self.cell(*knot), KNOT);
+        }
+        frame.paint(self.cell(self.bridge.head), HEAD);
+    }
+}
+
+/// Animates the ten knot rope of the second part.
+#[wasm_bindgen]
+pub fn start(input: &str) -> Result<(), JsValue> {
+    let motions = Motions::parse(input).map_err(visualize::js_error)?;
+    visualize::animate("canvas", RopeAnimation::new(&motions, 9))
+}
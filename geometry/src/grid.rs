@@ -0,0 +1,201 @@
+use std::ops::{Index, IndexMut};
+
+use color_eyre::eyre::{eyre, WrapErr};
+
+use crate::Point;
+
+/// A rectangle of cells, addressed by points with `x` as the column and `y` as the row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone + Default> Grid<T> {
+    /// A grid of the given size filled with the default cell.
+    pub fn new(width: usize, height: usize) -> Self {
+        Grid {
+            width,
+            height,
+            cells: vec![T::default(); width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Reads a grid with one row per line and one cell per character.
+    ///
+    /// Every line must be as long as the first one. Errors of `cell` are reported
+    /// with the line and column of the offending character.
+    pub fn parse(
+        input: &str,
+        mut cell: impl FnMut(char) -> color_eyre::Result<T>,
+    ) -> color_eyre::Result<Self> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+        for (y, line) in input.lines().enumerate() {
+            let before = cells.len();
+            for (x, c) in line.chars().enumerate() {
+                let value =
+                    cell(c).wrap_err_with(|| format!("line {}, column {}", y + 1, x + 1))?;
+                cells.push(value);
+            }
+            let length = cells.len() - before;
+            match width {
+                None => width = Some(length),
+                Some(width) if width != length => {
+                    return Err(eyre!(
+                        "line {} has {length} cells, but the first line has {width}",
+                        y + 1
+                    ))
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+        match width {
+            Some(width) if width > 0 => Ok(Grid {
+                width,
+                height,
+                cells,
+            }),
+            _ => Err(eyre!("the grid is empty")),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point<usize>) -> bool {
+        point.x < self.width && point.y < self.height
+    }
+
+    pub fn get(&self, point: Point<usize>) -> Option<&T> {
+        self.contains(point)
+            .then(|| &self.cells[point.y * self.width + point.x])
+    }
+
+    pub fn get_mut(&mut self, point: Point<usize>) -> Option<&mut T> {
+        self.contains(point)
+            .then(|| &mut self.cells[point.y * self.width + point.x])
+    }
+
+    /// The up to four orthogonally adjacent points inside of the grid.
+    pub fn neighbours(&self, point: Point<usize>) -> impl Iterator<Item = Point<usize>> + '_ {
+        point.neighbours().filter(|p| self.contains(*p))
+    }
+
+    /// The up to eight orthogonally or diagonally adjacent points inside of the grid.
+    pub fn surrounding(&self, point: Point<usize>) -> impl Iterator<Item = Point<usize>> + '_ {
+        point.surrounding().filter(|p| self.contains(*p))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    /// Every cell together with its point, row by row.
+    pub fn indexed_iter(&self) -> impl Iterator<Item = (Point<usize>, &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, cell)| (Point::new(i % width, i / width), cell))
+    }
+
+    /// Adds a row below the last one.
+    pub fn push_row(&mut self, row: Vec<T>) -> color_eyre::Result<()> {
+        if row.len() != self.width {
+            return Err(eyre!(
+                "the row has {} cells, but the grid is {} wide",
+                row.len(),
+                self.width
+            ));
+        }
+        self.cells.extend(row);
+        self.height += 1;
+        Ok(())
+    }
+}
+
+impl<T> Index<Point<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point<usize>) -> &T {
+        self.get(point).unwrap_or_else(|| {
+            panic!(
+                "{point:?} is outside of the {}x{} grid",
+                self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Point<usize>> for Grid<T> {
+    fn index_mut(&mut self, point: Point<usize>) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside of the {width}x{height} grid"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn digits(input: &str) -> color_eyre::Result<Grid<u32>> {
+        Grid::parse(input, |c| {
+            c.to_digit(10).ok_or_else(|| eyre!("{c:?} is no digit"))
+        })
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = digits("123\n456\n").unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point::new(2, 1)], 6);
+        assert_eq!(grid.row(1), &[4, 5, 6]);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec![2, 5]);
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+    }
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            digits("123\n45\n").unwrap_err().to_string(),
+            "line 2 has 2 cells, but the first line has 3"
+        );
+        assert_eq!(
+            digits("123\n4x6\n").unwrap_err().to_string(),
+            "line 2, column 2"
+        );
+        assert!(digits("").is_err());
+    }
+    #[test]
+    fn test_neighbours() {
+        let grid = digits("123\n456\n").unwrap();
+        assert_eq!(
+            grid.neighbours(Point::new(2, 1)).collect::<Vec<_>>(),
+            vec![Point::new(2, 0), Point::new(1, 1)]
+        );
+        assert_eq!(grid.surrounding(Point::new(1, 0)).count(), 5);
+    }
+}
@@ -0,0 +1,7 @@
+//! Points, directions and grids shared by the puzzles that walk over a plane.
+
+mod grid;
+mod point;
+
+pub use grid::Grid;
+pub use point::{Direction, Point, Scalar};
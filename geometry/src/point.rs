@@ -0,0 +1,191 @@
+use std::{
+    fmt::Debug,
+    hash::Hash,
+    ops::{Add, AddAssign, Sub, SubAssign},
+};
+
+/// A number that can be used as the coordinate of a [`Point`].
+pub trait Scalar:
+    Copy + Debug + Default + Ord + Hash + Add<Output = Self> + Sub<Output = Self>
+{
+    /// Distance between two values, which is never negative.
+    fn abs_diff(self, other: Self) -> Self;
+    /// `self + delta`, or `None` if the result does not fit into `Self`.
+    fn checked_offset(self, delta: i8) -> Option<Self>;
+}
+
+macro_rules! unsigned_scalar {
+    ($($t:ty),*) => {$(
+        impl Scalar for $t {
+            fn abs_diff(self, other: Self) -> Self {
+                <$t>::abs_diff(self, other)
+            }
+            fn checked_offset(self, delta: i8) -> Option<Self> {
+                self.checked_add_signed(delta.into())
+            }
+        }
+    )*};
+}
+
+macro_rules! signed_scalar {
+    ($($t:ty),*) => {$(
+        impl Scalar for $t {
+            fn abs_diff(self, other: Self) -> Self {
+                (self - other).abs()
+            }
+            fn checked_offset(self, delta: i8) -> Option<Self> {
+                self.checked_add(delta.into())
+            }
+        }
+    )*};
+}
+
+unsigned_scalar!(u8, u16, u32, u64, usize);
+signed_scalar!(i8, i16, i32, i64, isize);
+
+/// One of the four orthogonal directions on a grid.
+///
+/// `y` grows downwards like the rows of the puzzle input, so `Up` lowers it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// Change of `(x, y)` when taking a step in this direction.
+    pub fn offset(self) -> (i8, i8) {
+        match self {
+            Direction::Up => (0, -1),
+            Direction::Right => (1, 0),
+            Direction::Down => (0, 1),
+            Direction::Left => (-1, 0),
+        }
+    }
+}
+
+/// Offsets of the eight surrounding points, orthogonal and diagonal.
+const SURROUNDING: [(i8, i8); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// A point in the plane, with `x` as the column and `y` as the row.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T: Scalar> Point<T> {
+    pub fn new(x: T, y: T) -> Self {
+        Point { x, y }
+    }
+
+    /// The point `dx` columns and `dy` rows away, if it can be represented.
+    pub fn offset(self, dx: i8, dy: i8) -> Option<Self> {
+        Some(Point {
+            x: self.x.checked_offset(dx)?,
+            y: self.y.checked_offset(dy)?,
+        })
+    }
+
+    /// The neighbouring point in `direction`, if it can be represented.
+    pub fn step(self, direction: Direction) -> Option<Self> {
+        let (dx, dy) = direction.offset();
+        self.offset(dx, dy)
+    }
+
+    /// The up to four orthogonally adjacent points.
+    pub fn neighbours(self) -> impl Iterator<Item = Self> {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(direction))
+    }
+
+    /// The up to eight orthogonally or diagonally adjacent points.
+    pub fn surrounding(self) -> impl Iterator<Item = Self> {
+        SURROUNDING
+            .into_iter()
+            .filter_map(move |(dx, dy)| self.offset(dx, dy))
+    }
+
+    /// Sum of the horizontal and vertical distance to `other`.
+    pub fn manhattan(self, other: Self) -> T {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+impl<T: Scalar + From<i8>> From<Direction> for Point<T> {
+    fn from(direction: Direction) -> Self {
+        let (dx, dy) = direction.offset();
+        Point::new(dx.into(), dy.into())
+    }
+}
+
+impl<T: Scalar> Add for Point<T> {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Scalar> Sub for Point<T> {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Scalar> AddAssign for Point<T> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<T: Scalar> SubAssign for Point<T> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_neighbours_stay_representable() {
+        let corner: Point<usize> = Point::new(0, 0);
+        assert_eq!(
+            corner.neighbours().collect::<Vec<_>>(),
+            vec![Point::new(1, 0), Point::new(0, 1)]
+        );
+        assert_eq!(corner.surrounding().count(), 3);
+        assert_eq!(Point::new(5i32, 5).surrounding().count(), 8);
+    }
+    #[test]
+    fn test_manhattan() {
+        assert_eq!(Point::new(8i64, 7).manhattan(Point::new(2, 10)), 9);
+        assert_eq!(Point::new(2u32, 10).manhattan(Point::new(8, 7)), 9);
+    }
+    #[test]
+    fn test_direction() {
+        assert_eq!(Point::<i32>::from(Direction::Up), Point::new(0, -1));
+        assert_eq!(Point::new(3u8, 0).step(Direction::Up), None);
+    }
+}
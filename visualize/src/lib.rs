@@ -0,0 +1,109 @@
+use std::{cell::RefCell, rc::Rc};
+
+use geometry::Point;
+use wasm_bindgen::{prelude::Closure, JsCast, JsValue};
+use webgl::{Rectangle, Webgl};
+
+mod webgl;
+
+/// Red, green, blue and alpha, each between 0 and 1.
+pub type Color = [f32; 4];
+
+/// The coloured cells of one step, cells which are not painted stay blank.
+#[derive(Debug, Default)]
+pub struct Frame {
+    cells: Vec<(Point<usize>, Color)>,
+}
+
+impl Frame {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Colours the cell at `point`, painting over earlier colours of the same cell.
+    pub fn paint(&mut self, point: Point<usize>, color: Color) {
+        self.cells.push((point, color));
+    }
+    pub fn cells(&self) -> &[(Point<usize>, Color)] {
+        &self.cells
+    }
+    pub fn clear(&mut self) {
+        self.cells.clear();
+    }
+}
+
+/// A simulation which can be animated step by step.
+pub trait Visualize {
+    /// Width and height of the area covered by every frame, in cells.
+    fn size(&self) -> (usize, usize);
+    /// Advances the simulation by one step, returns `false` once it is finished.
+    fn step(&mut self) -> bool;
+    /// Paints the current state of the simulation.
+    fn draw(&self, frame: &mut Frame);
+}
+
+/// The longer side of the canvas in pixels, the shorter one keeps the aspect ratio of the cells.
+const CANVAS_SIZE: usize = 800;
+
+fn window() -> web_sys::Window {
+    web_sys::window().expect("no global `window` exists")
+}
+
+fn request_animation_frame(f: &Closure<dyn FnMut()>) {
+    window()
+        .request_animation_frame(f.as_ref().unchecked_ref())
+        .expect("should register `requestAnimationFrame` OK");
+}
+
+fn draw(webgl: &Webgl, frame: &Frame) -> Result<(), JsValue> {
+    webgl.clear();
+    for (point, color) in frame.cells() {
+        webgl.draw_rectangle(&Rectangle {
+            pos: Point::new(point.x as i32, point.y as i32),
+            width: 1,
+            height: 1,
+            color: *color,
+        })?;
+    }
+    Ok(())
+}
+
+/// Animates `visualization` on the canvas with the id `canvas`, one step per animation frame.
+pub fn animate(canvas: &str, mut visualization: impl Visualize + 'static) -> Result<(), JsValue> {
+    console_error_panic_hook::set_once();
+    let canvas = window()
+        .document()
+        .ok_or("no document exists")?
+        .get_element_by_id(canvas)
+        .ok_or_else(|| format!("no element with the id `{canvas}` exists"))?
+        .dyn_into::<web_sys::HtmlCanvasElement>()?;
+    let (width, height) = visualization.size();
+    let scale = (CANVAS_SIZE / width.max(height).max(1)).max(1);
+    canvas.set_width((width * scale) as u32);
+    canvas.set_height((height * scale) as u32);
+    let webgl = Webgl::new(canvas, width as i32, height as i32)?;
+
+    let mut frame = Frame::new();
+    visualization.draw(&mut frame);
+    draw(&webgl, &frame)?;
+
+    let f = Rc::new(RefCell::new(None));
+    let g = f.clone();
+    *g.borrow_mut() = Some(Closure::new(move || {
+        let running = visualization.step();
+        frame.clear();
+        visualization.draw(&mut frame);
+        draw(&webgl, &frame).unwrap();
+        if running {
+            request_animation_frame(f.borrow().as_ref().unwrap());
+        } else {
+            let _ = f.borrow_mut().take();
+        }
+    }));
+    request_animation_frame(g.borrow().as_ref().unwrap());
+    Ok(())
+}
+
+/// Turns an error of a solver into an exception for JavaScript.
+pub fn js_error(error: color_eyre::Report) -> JsValue {
+    JsValue::from_str(&format!("{error:#}"))
+}
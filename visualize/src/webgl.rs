@@ -1,5 +1,4 @@
-use derive_more::Add;
-use js_sys::Math;
+use geometry::Point;
 use wasm_bindgen::prelude::*;
 use wasm_bindgen::JsValue;
 use web_sys::HtmlCanvasElement;
@@ -8,14 +7,9 @@ use web_sys::WebGlProgram;
 use web_sys::WebGlShader;
 use web_sys::WebGlUniformLocation;
 
-#[derive(Add, Clone, Debug)]
-pub struct IntVec2 {
-    pub x: i32,
-    pub y: i32,
-}
 #[derive(Debug)]
 pub struct Rectangle {
-    pub pos: IntVec2,
+    pub pos: Point<i32>,
     pub width: i32,
     pub height: i32,
     pub color: [f32; 4],
@@ -23,8 +17,6 @@ pub struct Rectangle {
 
 pub struct Webgl {
     context: WebGl2RenderingContext,
-    position_attribute_location: i32,
-    resolution_uniform_location: WebGlUniformLocation,
     color_location: WebGlUniformLocation,
 }
 impl Webgl {
@@ -81,28 +73,14 @@ impl Webgl {
 
         Ok(Webgl {
             context,
-            position_attribute_location,
-            resolution_uniform_location,
             color_location,
         })
     }
     pub fn draw_rectangle(&self, rect: &Rectangle) -> Result<(), JsValue> {
-        let a = rect.pos.clone();
-        let b = a.clone()
-            + IntVec2 {
-                x: rect.width,
-                y: 0,
-            };
-        let c = a.clone()
-            + IntVec2 {
-                x: rect.width,
-                y: rect.height,
-            };
-        let d = a.clone()
-            + IntVec2 {
-                x: 0,
-                y: rect.height,
-            };
+        let a = rect.pos;
+        let b = a + Point::new(rect.width, 0);
+        let c = a + Point::new(rect.width, rect.height);
+        let d = a + Point::new(0, rect.height);
 
         let verticies: [i32; 12] = [a.x, a.y, b.x, b.y, c.x, c.y, a.x, a.y, c.x, c.y, d.x, d.y];
 
@@ -117,7 +95,7 @@ impl Webgl {
         self.context
             .uniform4fv_with_f32_array(Some(&self.color_location), rect.color.as_ref());
 
-        let vert_count = verticies.len() as i32 / 2 as i32;
+        let vert_count = verticies.len() as i32 / 2;
         self.draw(vert_count);
 
         Ok(())
@@ -132,18 +110,6 @@ impl Webgl {
     }
 }
 
-fn random_color() -> [f32; 4] {
-    [
-        Math::random() as f32,
-        Math::random() as f32,
-        Math::random() as f32,
-        1.0,
-    ]
-}
-fn random_int(max: i32) -> i32 {
-    (Math::random() * max as f64) as i32 + 1 as i32
-}
-
 pub fn compile_shader(
     context: &WebGl2RenderingContext,
     shader_type: u32,
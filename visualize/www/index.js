@@ -0,0 +1,8 @@
+// For more comments about what's going on here, check out the `hello_world`
+// example.
+import('./pkg')
+  .then(async (wasm) => {
+    const input = await fetch(INPUT_FILE).then((response) => response.text());
+    wasm.start(input);
+  })
+  .catch(console.error);
@@ -3,6 +3,12 @@ const HtmlWebpackPlugin = require('html-webpack-plugin');
 const webpack = require('webpack');
 const WasmPackPlugin = require("@wasm-tool/wasm-pack-plugin");
 
+// The day to animate, e.g. `DAY=9 npm run serve`.
+const day = process.env.DAY || '14';
+const crate = path.resolve(__dirname, '..', '..', `day${day}`);
+// Inputs are served from `$AOC_INPUT_DIR` or the `inputs` directory like for the runner.
+const inputs = process.env.AOC_INPUT_DIR || path.resolve(__dirname, '..', '..', 'inputs');
+
 module.exports = {
     entry: './index.js',
     output: {
@@ -14,8 +20,12 @@ module.exports = {
             template: 'index.html'
         }),
       new WasmPackPlugin({
-          crateDirectory: path.resolve(__dirname, ".")
+          crateDirectory: crate,
+          outDir: path.resolve(__dirname, 'pkg')
       }),
+        new webpack.DefinePlugin({
+          INPUT_FILE: JSON.stringify(`day${day.padStart(2, '0')}.txt`)
+        }),
         // Have this example work in Edge which doesn't ship `TextEncoder` or
         // `TextDecoder` at this time.
         new webpack.ProvidePlugin({
@@ -23,6 +33,9 @@ module.exports = {
           TextEncoder: ['text-encoding', 'TextEncoder']
         })
     ],
+    devServer: {
+        static: inputs,
+    },
     mode: 'development',
     experiments: {
         asyncWebAssembly: true